@@ -0,0 +1 @@
+mxpy contract build && cargo test -p apc_sales --test mod -- --ignored
\ No newline at end of file
@@ -22,9 +22,96 @@ pub struct Auction<M: ManagedTypeApi> {
     pub price: BigUint<M>,
 
     pub start_timestamp: u64,
+    /** 0 if the sale never closes */
+    pub end_timestamp: u64,
 
     pub current_quantity: BigUint<M>,
     pub max_quantity: BigUint<M>,
+
+    /** 0 if a wallet can buy every item */
+    pub max_per_wallet: BigUint<M>,
+
+    pub status: AuctionStatus,
+
+    /** Lists the items, receives the proceeds and the retired items */
+    pub seller: ManagedAddress<M>,
+}
+
+/** Set through the endpoints, unlike the `SaleStatus` derived from the auction */
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    TypeAbi,
+    Clone,
+    Copy,
+    Debug,
+)]
+pub enum AuctionStatus {
+    Active,
+    Paused,
+    // The items were retired, the auction is hidden from the listing views
+    Hidden,
+    // Archived once its proceeds are settled, it can't be changed anymore
+    Closed,
+}
+
+impl AuctionStatus {
+    /** The legacy contract hid an auction by setting its `max_quantity` to 0 */
+    fn from_max_quantity<M: ManagedTypeApi>(max_quantity: &BigUint<M>) -> Self {
+        if *max_quantity == 0 {
+            AuctionStatus::Hidden
+        } else {
+            AuctionStatus::Active
+        }
+    }
+}
+
+/** The items of the auctions hidden that way were retired, whatever their `current_quantity` says */
+fn migrate_current_quantity<M: ManagedTypeApi>(
+    current_quantity: BigUint<M>,
+    max_quantity: &BigUint<M>,
+) -> BigUint<M> {
+    if *max_quantity == 0 {
+        BigUint::zero()
+    } else {
+        current_quantity
+    }
+}
+
+/** Layout of `Auction` in the storage version 0 */
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct LegacyAuction<M: ManagedTypeApi> {
+    pub input_token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub input_token_nonce: u64,
+    pub output_token_id: TokenIdentifier<M>,
+    pub output_token_nonce: u64,
+    pub price: BigUint<M>,
+    pub start_timestamp: u64,
+    pub current_quantity: BigUint<M>,
+    pub max_quantity: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> LegacyAuction<M> {
+    pub fn migrate(self, seller: ManagedAddress<M>) -> Auction<M> {
+        Auction {
+            input_token_id: self.input_token_id,
+            input_token_nonce: self.input_token_nonce,
+            output_token_id: self.output_token_id,
+            output_token_nonce: self.output_token_nonce,
+            price: self.price,
+            start_timestamp: self.start_timestamp,
+            end_timestamp: 0,
+            status: AuctionStatus::from_max_quantity(&self.max_quantity),
+            current_quantity: migrate_current_quantity(self.current_quantity, &self.max_quantity),
+            max_quantity: self.max_quantity,
+            max_per_wallet: BigUint::zero(),
+            seller,
+        }
+    }
 }
 
 #[derive(
@@ -41,4 +128,147 @@ pub struct Auction<M: ManagedTypeApi> {
 pub struct AuctionStats<M: ManagedTypeApi> {
     pub auction: Auction<M>,
     pub id: u64,
+    pub kind: AuctionKind,
+    pub status: SaleStatus,
+}
+
+/** Derived from the auction and the current block timestamp */
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    TypeAbi,
+    Clone,
+    Copy,
+    Debug,
+)]
+pub enum SaleStatus {
+    Upcoming,
+    Live,
+    Ended,
+    SoldOut,
+    Hidden,
+    Paused,
+    Closed,
+}
+
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    TypeAbi,
+    Clone,
+    Copy,
+    Debug,
+)]
+pub enum AuctionKind {
+    // Sold with `buy`, at `Auction::price` per item
+    FixedPrice,
+    // Sold as a single lot to the highest bidder once `Auction::end_timestamp` is reached
+    English,
+    // Sold with `buy`, at a unit price going down over time, see `DutchAuction`
+    Dutch,
+    // Sold with `buy`, at `Auction::price` per bundle of several tokens.
+    // The output token of the auction is the first component of the bundle.
+    Bundle,
+    // Sold with `buy`, at `Auction::price` per item drawn at random from a pool of nonces.
+    // The output nonce of the auction is 0.
+    MysteryBox,
+    // Sold with `buy`, at a unit price growing with each item sold, see `BondingCurve`
+    BondingCurve,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Debug)]
+pub struct EnglishAuction<M: ManagedTypeApi> {
+    /** aka minimum amount of the first bid, stored in `Auction::price` too */
+    pub reserve_price: BigUint<M>,
+    pub min_bid_increment: BigUint<M>,
+
+    /** 0 while nobody has bid */
+    pub highest_bid: BigUint<M>,
+    pub highest_bidder: ManagedAddress<M>,
+
+    pub claimed: bool,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Debug)]
+pub struct DutchAuction<M: ManagedTypeApi> {
+    /** the start price is stored in `Auction::price` */
+    pub floor_price: BigUint<M>,
+    /** when the price reaches `floor_price` */
+    pub floor_timestamp: u64,
+
+    /** 0 for a linear decrease */
+    pub price_step_duration: u64,
+}
+
+/**
+ * Unit price of the item sold after `n` others, `n` being `max_quantity - current_quantity`.
+ * The start price is stored in `Auction::price`.
+ */
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Debug)]
+pub enum BondingCurve<M: ManagedTypeApi> {
+    /** `price + n * increment` */
+    Linear { increment: BigUint<M> },
+    /** `price * (1 + growth_bps / 10_000)^n` */
+    Exponential { growth_bps: u64 },
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Debug)]
+pub struct Presale<M: ManagedTypeApi> {
+    /** the presale runs from `Auction::start_timestamp` until this timestamp */
+    pub public_start_timestamp: u64,
+
+    /** 0 to keep the price of the auction */
+    pub price: BigUint<M>,
+    /** 0 to keep the limit of the auction */
+    pub max_per_wallet: BigUint<M>,
+}
+
+/** From `from_timestamp` until the next tier, `buy` sells at `price` in the input token */
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    TypeAbi,
+    Clone,
+    Debug,
+)]
+pub struct PriceTier<M: ManagedTypeApi> {
+    pub from_timestamp: u64,
+    pub price: BigUint<M>,
+}
+
+/** Proves that an address belongs to the allowlist Merkle tree of an auction, see `merkle` */
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Debug)]
+pub struct AllowlistProof<M: ManagedTypeApi> {
+    /** 0 if the address has no allowance of its own */
+    pub allowance: u64,
+    pub proof: ManagedVec<M, ManagedByteArray<M, 32>>,
+}
+
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    PartialEq,
+    TypeAbi,
+    Clone,
+    Debug,
+)]
+pub struct FeeShare<M: ManagedTypeApi> {
+    pub payee: ManagedAddress<M>,
+    /** part of each sale credited to the payee, in basis points */
+    pub bps: u64,
 }
@@ -1,15 +1,36 @@
 #![no_std]
 #![no_main]
 
-use auction::{Auction, AuctionStats};
+use auction::{
+    AllowlistProof, Auction, AuctionKind, AuctionStats, AuctionStatus, BondingCurve, DutchAuction,
+    EnglishAuction, FeeShare, LegacyAuction, Presale, PriceTier, SaleStatus,
+};
 
 multiversx_sc::imports!();
 
+use roles::Role;
+
 pub mod auction;
+pub mod events;
+// off-chain only, the contract checks the proofs with the crypto API
+#[cfg(not(target_arch = "wasm32"))]
+pub mod merkle;
+pub mod roles;
 
 pub const STARTING_AUCTION_ID: u64 = 1;
+pub const MAX_BPS: u64 = 10_000;
+/** Highest number of items of an exponential bonding curve, bounding the exponents of its cost */
+pub const MAX_EXPONENTIAL_CURVE_QUANTITY: u64 = 500;
+/** Highest share of every sale the platform fee can take */
+pub const MAX_PLATFORM_FEE_BPS: u64 = 1_000;
+
+/** Version 0 is the layout of `LegacyAuction`, see `init` */
+pub const STORAGE_VERSION: u32 = 1;
+/** Maximum number of auctions returned by a page of the listing views */
+pub const MAX_PAGE_SIZE: usize = 100;
 
 pub const ERR_SALE_IS_NOT_OPENED_YET: &str = "The sale is not opened yet";
+pub const ERR_SALE_IS_CLOSED: &str = "The sale is closed";
 pub const ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH: &str =
     "The payment is invalid. Wrong token sent.";
 pub const ERR_INVALID_PAYMENT_TOKEN_NONCE_MISMATCH: &str =
@@ -17,29 +38,308 @@ pub const ERR_INVALID_PAYMENT_TOKEN_NONCE_MISMATCH: &str =
 pub const ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH: &str =
     "The payment is invalid. Wrong amount sent.";
 pub const ERR_NOT_ENOUGHT_ITEMS: &str = "Cannot fulfill your order. Try to buy less items.";
+pub const ERR_MAX_PER_WALLET_EXCEEDED: &str =
+    "Cannot fulfill your order. You have reached the limit of items per wallet.";
+pub const ERR_NOT_ALLOWLISTED: &str = "The sale is only opened to the allowlist for now.";
+pub const ERR_INVALID_ALLOWLIST_PROOF: &str = "The allowlist proof is invalid.";
+pub const ERR_PRESALE_BAD_PUBLIC_START_TIMESTAMP: &str =
+    "The public sale must start after the start timestamp.";
 pub const ERR_INVALID_AUCTION_ID: &str = "Auction ID invalid.";
 pub const ERR_CREATE_AUCTION_BAD_EGLD_NONCE: &str =
     "When creating an auction with egld, you must set the nonce to 0.";
 pub const ERR_CREATE_AUCTION_BAD_PRICE: &str = "The price cannot be set to 0";
 pub const ERR_RETIRING_TOO_MUCH_TOKENS: &str = "Can't retire more items than the auction has.";
+pub const ERR_WRONG_AUCTION_KIND: &str = "This action is not available for this kind of auction.";
+pub const ERR_CREATE_AUCTION_BAD_END_TIMESTAMP: &str =
+    "The end timestamp must be after the start timestamp.";
+pub const ERR_CREATE_AUCTION_BAD_BID_INCREMENT: &str =
+    "The minimum bid increment cannot be set to 0";
+pub const ERR_BIDDING_IS_OVER: &str = "The bidding is over.";
+pub const ERR_BIDDING_IS_NOT_OVER: &str = "The bidding is not over yet.";
+pub const ERR_BID_TOO_LOW: &str = "The bid is too low.";
+pub const ERR_AUCTION_ALREADY_CLAIMED: &str = "The auction has already been claimed.";
+pub const ERR_AUCTION_HAS_BIDS: &str = "Can't change the items of an auction that has bids.";
+pub const ERR_CREATE_AUCTION_BAD_FLOOR_PRICE: &str =
+    "The floor price must be between 1 and the start price.";
+pub const ERR_CREATE_AUCTION_BAD_BUNDLE_QUANTITY: &str =
+    "Each deposited amount must be a non-zero multiple of the bundle quantity.";
+pub const ERR_MYSTERY_BOX_BAD_ITEM: &str =
+    "The items of a mystery box must be NFTs or SFTs of the same collection.";
+pub const ERR_PAYMENT_TOKEN_IS_THE_AUCTION_ONE: &str =
+    "The payment token of the auction itself cannot be removed.";
+pub const ERR_BUY_MANY_BAD_QUANTITY: &str = "Each auction of the checkout needs a quantity.";
+pub const ERR_BUY_MANY_MISSING_PAYMENT: &str =
+    "No payment of the checkout is accepted by one of the auctions.";
+pub const ERR_CART_BAD_QUANTITY: &str = "Each item of the cart needs a quantity.";
+pub const ERR_AUCTION_PAUSED: &str = "The auction is paused.";
+pub const ERR_MARKETPLACE_PAUSED: &str = "The marketplace is paused.";
+pub const ERR_AUCTION_NOT_ACTIVE: &str = "Only an active auction can be paused.";
+pub const ERR_AUCTION_NOT_PAUSED: &str = "The auction is not paused.";
+pub const ERR_AUCTION_ARCHIVED: &str = "The auction is archived.";
+pub const ERR_ARCHIVE_AUCTION_HAS_ITEMS: &str =
+    "Retire the items of the auction before archiving it.";
+pub const ERR_ARCHIVE_AUCTION_HAS_PROCEEDS: &str =
+    "Withdraw the proceeds of the auction before archiving it.";
+pub const ERR_PRICE_SCHEDULE_BAD_TIERS: &str = "The tiers need a price and increasing timestamps.";
+pub const ERR_CREATE_AUCTION_BAD_CURVE: &str = "The bonding curve must make the price grow.";
+pub const ERR_BONDING_CURVE_TOO_LONG: &str = "The exponential curve can't price that many items.";
+pub const ERR_UPDATE_AUCTION_HAS_SALES: &str =
+    "Can't change the payment token of an auction that has sales.";
+pub const ERR_NOT_AUCTION_SELLER: &str = "Only the seller of the auction can manage it.";
+pub const ERR_MISSING_ROLE: &str = "The caller doesn't have the role required by this endpoint.";
+pub const ERR_REVOKING_LAST_ADMIN: &str = "Can't revoke the last admin.";
+pub const ERR_FEE_SPLITS_BAD_SHARES: &str =
+    "Each share must be above 0 and the shares cannot exceed 10000 basis points in total.";
+pub const ERR_PLATFORM_FEE_TOO_HIGH: &str = "The platform fee cannot exceed 1000 basis points.";
 
 #[multiversx_sc::contract]
-pub trait EmptyContract {
+pub trait EmptyContract: events::EventsModule + roles::RolesModule {
     #[storage_mapper("auctions")]
     fn auctions(&self, id: u64) -> SingleValueMapper<Auction<Self::Api>>;
 
+    /** Same storage as `auctions`, read only to migrate the records of the version 0 */
+    #[storage_mapper("auctions")]
+    fn legacy_auctions(&self, id: u64) -> SingleValueMapper<LegacyAuction<Self::Api>>;
+
+    /** Paused auctions, and active auctions with items left */
+    #[storage_mapper("active_auctions")]
+    fn active_auctions(&self) -> SetMapper<u64>;
+
+    /** Sold out, hidden and archived auctions, one index per status, see `get_inactive_status` */
+    #[storage_mapper("inactive_auctions")]
+    fn inactive_auctions(&self, status: SaleStatus) -> SetMapper<u64>;
+
+    #[storage_mapper("auctions_by_seller")]
+    fn auctions_by_seller(&self, seller: &ManagedAddress) -> SetMapper<u64>;
+
+    #[storage_mapper("auctions_by_output_token")]
+    fn auctions_by_output_token(&self, token_id: &TokenIdentifier) -> SetMapper<u64>;
+
+    /** Auctions accepting the token, whatever its nonce */
+    #[storage_mapper("auctions_by_payment_token")]
+    fn auctions_by_payment_token(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SetMapper<u64>;
+
+    /**
+     * Stops every sale and bid, the admin endpoints keep working.
+     * Not the `PauseModule` of multiversx-sc-modules: its endpoints are reserved to the owner and log nothing,
+     * while the `Pauser` role toggles this flag.
+     */
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("storage_version")]
+    fn storage_version(&self) -> SingleValueMapper<u32>;
+
     #[storage_mapper("next_auction_id")]
     #[view(getNextAuctionId)]
     fn next_auction_id(&self) -> SingleValueMapper<u64>;
 
+    /** Total amount of input token paid by the buyers of an auction, minus the fees */
+    #[storage_mapper("collected_proceeds")]
+    fn collected_proceeds(&self, auction_id: u64) -> SingleValueMapper<BigUint>;
+
+    /** Part of the collected proceeds already sent to the seller */
+    #[storage_mapper("withdrawn_proceeds")]
+    fn withdrawn_proceeds(&self, auction_id: u64) -> SingleValueMapper<BigUint>;
+
+    /** Unit prices in the payment tokens accepted besides the input token of the auction */
+    #[storage_mapper("extra_payment_tokens")]
+    fn extra_payment_tokens(
+        &self,
+        auction_id: u64,
+    ) -> MapMapper<(EgldOrEsdtTokenIdentifier, u64), BigUint>;
+
+    /** Proceeds in the extra payment tokens, not withdrawn yet */
+    #[storage_mapper("extra_pending_proceeds")]
+    fn extra_pending_proceeds(
+        &self,
+        auction_id: u64,
+    ) -> MapMapper<(EgldOrEsdtTokenIdentifier, u64), BigUint>;
+
+    /** Empty for the auctions created before kinds existed, which decodes to FixedPrice */
+    #[storage_mapper("auction_kind")]
+    fn auction_kind(&self, auction_id: u64) -> SingleValueMapper<AuctionKind>;
+
+    #[storage_mapper("english_auctions")]
+    fn english_auctions(&self, auction_id: u64) -> SingleValueMapper<EnglishAuction<Self::Api>>;
+
+    #[storage_mapper("dutch_auctions")]
+    fn dutch_auctions(&self, auction_id: u64) -> SingleValueMapper<DutchAuction<Self::Api>>;
+
+    #[storage_mapper("bonding_curves")]
+    fn bonding_curves(&self, auction_id: u64) -> SingleValueMapper<BondingCurve<Self::Api>>;
+
+    /** Tokens delivered for each item sold by a bundle auction */
+    #[storage_mapper("bundles")]
+    fn bundles(
+        &self,
+        auction_id: u64,
+    ) -> SingleValueMapper<ManagedVec<EsdtTokenPayment<Self::Api>>>;
+
+    /** `(nonce, remaining)` entries of a mystery box, one per deposited nonce */
+    #[storage_mapper("mystery_box_pool")]
+    fn mystery_box_pool(&self, auction_id: u64) -> VecMapper<(u64, u64)>;
+
+    /** Sorted by `from_timestamp`, `Auction::price` applies before the first tier */
+    #[storage_mapper("price_schedules")]
+    fn price_schedules(
+        &self,
+        auction_id: u64,
+    ) -> SingleValueMapper<ManagedVec<PriceTier<Self::Api>>>;
+
+    #[storage_mapper("presales")]
+    fn presales(&self, auction_id: u64) -> SingleValueMapper<Presale<Self::Api>>;
+
+    #[view(getAllowlist)]
+    #[storage_mapper("allowlist")]
+    fn allowlist(&self, auction_id: u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    /** Empty if the allowlist has no Merkle tree */
+    #[storage_mapper("allowlist_merkle_root")]
+    fn allowlist_merkle_root(
+        &self,
+        auction_id: u64,
+    ) -> SingleValueMapper<ManagedByteArray<Self::Api, 32>>;
+
+    #[view(getDefaultFeeSplits)]
+    #[storage_mapper("default_fee_splits")]
+    fn default_fee_splits(&self) -> SingleValueMapper<ManagedVec<FeeShare<Self::Api>>>;
+
+    /** Empty if the auction uses the default fee splits */
+    #[storage_mapper("auction_fee_splits")]
+    fn auction_fee_splits(
+        &self,
+        auction_id: u64,
+    ) -> SingleValueMapper<ManagedVec<FeeShare<Self::Api>>>;
+
+    /** Whether the creator royalties of the output token are paid on each sale */
+    #[view(isHonoringRoyalties)]
+    #[storage_mapper("honor_royalties")]
+    fn honor_royalties(&self, auction_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("accrued_fees")]
+    fn accrued_fees(
+        &self,
+        payee: &ManagedAddress,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+    ) -> SingleValueMapper<BigUint>;
+
+    /** Tokens for which the payee has accrued fees not claimed yet */
+    #[storage_mapper("accrued_fee_tokens")]
+    fn accrued_fee_tokens(
+        &self,
+        payee: &ManagedAddress,
+    ) -> UnorderedSetMapper<(EgldOrEsdtTokenIdentifier, u64)>;
+
+    /** Taken on every sale before the fee splits, none if empty */
+    #[view(getPlatformFee)]
+    #[storage_mapper("platform_fee")]
+    fn platform_fee(&self) -> SingleValueMapper<FeeShare<Self::Api>>;
+
+    /** Everything credited to the seller, withdrawn or not */
+    #[storage_mapper("seller_earnings")]
+    fn seller_earnings(
+        &self,
+        seller: &ManagedAddress,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("seller_earning_tokens")]
+    fn seller_earning_tokens(
+        &self,
+        seller: &ManagedAddress,
+    ) -> UnorderedSetMapper<(EgldOrEsdtTokenIdentifier, u64)>;
+
+    #[storage_mapper("purchased_quantity")]
+    fn purchased_quantity(
+        &self,
+        auction_id: u64,
+        buyer: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+
     #[init]
     fn init(&self) {
         if self.next_auction_id().is_empty() {
             self.next_auction_id().set(STARTING_AUCTION_ID);
+        } else if self.storage_version().get() < 1 {
+            self.migrate_legacy_auctions();
+            self.seed_legacy_proceeds();
+            self.index_auctions();
+        }
+
+        // the deployer, or the owner of a contract deployed before the roles
+        if self.role_holders(Role::Admin).is_empty() {
+            self.role_holders(Role::Admin)
+                .insert(self.blockchain().get_caller());
+        }
+
+        self.storage_version().set(STORAGE_VERSION);
+    }
+
+    fn migrate_legacy_auctions(&self) {
+        for auction_id in STARTING_AUCTION_ID..self.next_auction_id().get() {
+            let legacy_auction = self.legacy_auctions(auction_id).get();
+
+            self.auctions(auction_id)
+                .set(legacy_auction.migrate(self.blockchain().get_owner_address()));
+        }
+    }
+
+    /**
+     * The legacy contract kept no record of its withdrawals, so the proceeds still owed
+     * are the input tokens left on the contract, credited to the auctions in order up to their sales.
+     */
+    fn seed_legacy_proceeds(&self) {
+        for auction_id in STARTING_AUCTION_ID..self.next_auction_id().get() {
+            let auction = self.auctions(auction_id).get();
+
+            // hidden by setting its `max_quantity` to 0, its sales are unknown
+            if auction.max_quantity < auction.current_quantity {
+                continue;
+            }
+
+            let mut left = self
+                .blockchain()
+                .get_sc_balance(&auction.input_token_id, auction.input_token_nonce);
+
+            for previous_id in STARTING_AUCTION_ID..auction_id {
+                let previous = self.auctions(previous_id).get();
+
+                if previous.input_token_id == auction.input_token_id
+                    && previous.input_token_nonce == auction.input_token_nonce
+                {
+                    left -= self.collected_proceeds(previous_id).get();
+                }
+            }
+
+            let sales = (&auction.max_quantity - &auction.current_quantity) * &auction.price;
+
+            self.collected_proceeds(auction_id)
+                .set(core::cmp::min(sales, left));
+        }
+    }
+
+    fn index_auctions(&self) {
+        for auction_id in STARTING_AUCTION_ID..self.next_auction_id().get() {
+            let auction = self.auctions(auction_id).get();
+
+            self.auctions_by_seller(&auction.seller).insert(auction_id);
+            self.auctions_by_output_token(&auction.output_token_id)
+                .insert(auction_id);
+            self.auctions_by_payment_token(&auction.input_token_id)
+                .insert(auction_id);
+
+            for (token_id, _) in self.extra_payment_tokens(auction_id).keys() {
+                self.auctions_by_payment_token(&token_id).insert(auction_id);
+            }
+
+            self.save_auction(auction_id, auction);
         }
     }
 
-    #[only_owner]
     #[payable("*")]
     #[endpoint(createAuction)]
     fn create_auction(
@@ -48,192 +348,2626 @@ pub trait EmptyContract {
         input_token_nonce: u64,
         price: BigUint,
         start_timestamp: u64,
+        opt_end_timestamp: OptionalValue<u64>,
+        opt_max_per_wallet: OptionalValue<BigUint>,
+        opt_bundle_quantity: OptionalValue<BigUint>,
     ) -> u64 {
-        if input_token_id.is_egld() {
-            require!(input_token_nonce == 0, ERR_CREATE_AUCTION_BAD_EGLD_NONCE);
-        }
+        let end_timestamp = opt_end_timestamp.into_option().unwrap_or_default();
 
-        require!(price > 0, ERR_CREATE_AUCTION_BAD_PRICE);
+        require!(
+            end_timestamp == 0 || end_timestamp > start_timestamp,
+            ERR_CREATE_AUCTION_BAD_END_TIMESTAMP
+        );
 
-        let payment = self.call_value().single_esdt();
+        let payments = self.call_value().all_esdt_transfers();
 
-        let new_auction_id = self.next_auction_id().get();
-        self.auctions(new_auction_id).set(Auction {
-            price,
-            start_timestamp,
+        if payments.len() <= 1 {
+            return self.store_new_auction(
+                input_token_id,
+                input_token_nonce,
+                price,
+                start_timestamp,
+                end_timestamp,
+                opt_max_per_wallet.into_option().unwrap_or_default(),
+                self.call_value().single_esdt(),
+            );
+        }
+
+        // a deposit of several tokens creates a bundle auction
+        let bundle_quantity = opt_bundle_quantity
+            .into_option()
+            .unwrap_or_else(|| BigUint::from(1u64));
+        let components = self.get_bundle_components(&payments, &bundle_quantity);
+        let first_component = components.get(0);
+
+        let auction_id = self.store_new_auction(
             input_token_id,
             input_token_nonce,
-            output_token_nonce: payment.token_nonce,
-            output_token_id: payment.token_identifier,
-            max_quantity: payment.amount.clone(),
-            current_quantity: payment.amount,
-        });
+            price,
+            start_timestamp,
+            end_timestamp,
+            opt_max_per_wallet.into_option().unwrap_or_default(),
+            EsdtTokenPayment::new(
+                first_component.token_identifier.clone(),
+                first_component.token_nonce,
+                bundle_quantity,
+            ),
+        );
 
-        self.next_auction_id().set(new_auction_id + 1);
+        self.create_bundle_event(auction_id, &components);
+        self.auction_kind(auction_id).set(AuctionKind::Bundle);
+        self.bundles(auction_id).set(components);
 
-        return new_auction_id;
+        return auction_id;
     }
 
-    #[only_owner]
-    #[endpoint(addTokenToAuction)]
-    #[payable("*")]
-    fn add_token_to_auction(&self, auction_id: u64) {
-        let mut auction = self.get_auction(auction_id);
-        let payment = self.call_value().single_esdt();
+    /** Divides a deposit of `quantity` bundles into the components of one bundle */
+    fn get_bundle_components(
+        &self,
+        payments: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+        quantity: &BigUint,
+    ) -> ManagedVec<EsdtTokenPayment<Self::Api>> {
+        require!(*quantity > 0, ERR_CREATE_AUCTION_BAD_BUNDLE_QUANTITY);
 
+        let mut components = ManagedVec::new();
+
+        for payment in payments.iter() {
+            require!(
+                &payment.amount % quantity == 0,
+                ERR_CREATE_AUCTION_BAD_BUNDLE_QUANTITY
+            );
+
+            components.push(EsdtTokenPayment::new(
+                payment.token_identifier.clone(),
+                payment.token_nonce,
+                &payment.amount / quantity,
+            ));
+        }
+
+        return components;
+    }
+
+    /**
+     * Sell the deposited tokens as one lot to the highest bidder.
+     * The lot is delivered by `claim`, once `end_timestamp` is reached.
+     */
+    #[payable("*")]
+    #[endpoint(createEnglishAuction)]
+    fn create_english_auction(
+        &self,
+        input_token_id: EgldOrEsdtTokenIdentifier,
+        input_token_nonce: u64,
+        reserve_price: BigUint,
+        min_bid_increment: BigUint,
+        start_timestamp: u64,
+        end_timestamp: u64,
+    ) -> u64 {
         require!(
-            &payment.token_identifier == &auction.output_token_id,
-            ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH
+            end_timestamp > start_timestamp,
+            ERR_CREATE_AUCTION_BAD_END_TIMESTAMP
         );
+        require!(min_bid_increment > 0, ERR_CREATE_AUCTION_BAD_BID_INCREMENT);
 
-        require!(
-            &payment.token_nonce == &auction.output_token_nonce,
-            ERR_INVALID_PAYMENT_TOKEN_NONCE_MISMATCH
+        let auction_id = self.store_new_auction(
+            input_token_id,
+            input_token_nonce,
+            reserve_price.clone(),
+            start_timestamp,
+            end_timestamp,
+            BigUint::zero(),
+            self.call_value().single_esdt(),
         );
 
-        auction.max_quantity += payment.amount.clone();
-        auction.current_quantity += payment.amount;
+        self.auction_kind(auction_id).set(AuctionKind::English);
+        self.english_auctions(auction_id).set(EnglishAuction {
+            reserve_price,
+            min_bid_increment,
+            highest_bid: BigUint::zero(),
+            highest_bidder: ManagedAddress::zero(),
+            claimed: false,
+        });
 
-        self.auctions(auction_id).set(auction);
+        return auction_id;
     }
 
-    #[only_owner]
-    #[endpoint(retireTokenFromAuction)]
-    fn retire_token_from_auction(&self, auction_id: u64, amount: &BigUint<Self::Api>) {
-        let mut auction = self.get_auction(auction_id);
+    /**
+     * Sell the deposited nonces of a collection at `price` each,
+     * every `buy` draws the nonces at random among the ones left.
+     * More nonces can be deposited later with `addTokenToAuction`.
+     */
+    #[payable("*")]
+    #[endpoint(createMysteryBoxAuction)]
+    fn create_mystery_box_auction(
+        &self,
+        input_token_id: EgldOrEsdtTokenIdentifier,
+        input_token_nonce: u64,
+        price: BigUint,
+        start_timestamp: u64,
+        opt_end_timestamp: OptionalValue<u64>,
+        opt_max_per_wallet: OptionalValue<BigUint>,
+    ) -> u64 {
+        let end_timestamp = opt_end_timestamp.into_option().unwrap_or_default();
 
         require!(
-            amount <= &auction.current_quantity,
-            ERR_RETIRING_TOO_MUCH_TOKENS
+            end_timestamp == 0 || end_timestamp > start_timestamp,
+            ERR_CREATE_AUCTION_BAD_END_TIMESTAMP
         );
 
-        self.send().direct_esdt(
-            &self.blockchain().get_caller(),
-            &auction.output_token_id,
-            auction.output_token_nonce,
-            amount,
+        let payments = self.call_value().all_esdt_transfers();
+
+        require!(!payments.is_empty(), ERR_MYSTERY_BOX_BAD_ITEM);
+
+        let mut quantity = BigUint::zero();
+        for payment in payments.iter() {
+            quantity += &payment.amount;
+        }
+
+        let auction_id = self.store_new_auction(
+            input_token_id,
+            input_token_nonce,
+            price,
+            start_timestamp,
+            end_timestamp,
+            opt_max_per_wallet.into_option().unwrap_or_default(),
+            EsdtTokenPayment::new(payments.get(0).token_identifier.clone(), 0, quantity),
         );
 
-        auction.current_quantity -= amount;
-        auction.max_quantity -= amount;
+        self.auction_kind(auction_id).set(AuctionKind::MysteryBox);
+        self.fill_mystery_box_pool(auction_id, &payments);
 
-        self.auctions(auction_id).set(auction);
+        return auction_id;
     }
 
-    #[only_owner]
-    #[endpoint(withdrawBalance)]
-    fn withdraw_balance(&self) {
-        let caller = self.blockchain().get_caller();
-
-        for auction_id in 1..self.next_auction_id().get() {
-            let auction = self.get_auction(auction_id);
-            let amount = (auction.max_quantity - auction.current_quantity) * auction.price;
+    /** Adds the deposited items to the pool of the mystery box, returns how many were added */
+    fn fill_mystery_box_pool(
+        &self,
+        auction_id: u64,
+        payments: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+    ) -> BigUint {
+        let collection = self.auctions(auction_id).get().output_token_id;
+        let mut pool = self.mystery_box_pool(auction_id);
+        let mut quantity = BigUint::zero();
 
-            self.send().direct(
-                &caller,
-                &auction.input_token_id,
-                auction.input_token_nonce,
-                &amount,
+        for payment in payments.iter() {
+            require!(
+                payment.token_identifier == collection && payment.token_nonce != 0,
+                ERR_MYSTERY_BOX_BAD_ITEM
             );
-        }
-    }
 
-    #[only_owner]
-    #[endpoint(hideAuction)]
-    fn hide_auction(&self, auction_id: u64) {
-        let mut auction = self.get_auction(auction_id);
+            let amount = match payment.amount.to_u64() {
+                Some(amount) => amount,
+                None => sc_panic!(ERR_MYSTERY_BOX_BAD_ITEM),
+            };
 
-        self.retire_token_from_auction(auction_id, &auction.current_quantity);
+            // a nonce deposited again adds to its entry, see `mystery_box_pool`
+            match (1..=pool.len()).find(|index| pool.get(*index).0 == payment.token_nonce) {
+                Some(index) => {
+                    let (nonce, remaining) = pool.get(index);
 
-        auction.max_quantity = BigUint::from(0u64);
+                    pool.set(index, &(nonce, remaining + amount));
+                }
+                None => {
+                    pool.push(&(payment.token_nonce, amount));
+                }
+            }
 
-        self.auctions(auction_id).set(auction);
+            quantity += &payment.amount;
+        }
+
+        return quantity;
     }
 
+    /**
+     * Sell the deposited tokens with a unit price going down from `start_price`
+     * to `floor_price` between `start_timestamp` and `floor_timestamp`.
+     * The price decreases linearly when `price_step_duration` is 0,
+     * otherwise it decreases once every `price_step_duration` seconds.
+     * The sale stays opened at `floor_price` after `floor_timestamp`, until `opt_end_timestamp` if any.
+     */
     #[payable("*")]
-    #[endpoint]
-    fn buy(&self, auction_id: u64) {
-        let mut auction = self.get_auction(auction_id);
+    #[endpoint(createDutchAuction)]
+    fn create_dutch_auction(
+        &self,
+        input_token_id: EgldOrEsdtTokenIdentifier,
+        input_token_nonce: u64,
+        start_price: BigUint,
+        floor_price: BigUint,
+        start_timestamp: u64,
+        floor_timestamp: u64,
+        price_step_duration: u64,
+        opt_end_timestamp: OptionalValue<u64>,
+        opt_max_per_wallet: OptionalValue<BigUint>,
+    ) -> u64 {
+        let end_timestamp = opt_end_timestamp.into_option().unwrap_or_default();
 
         require!(
-            self.blockchain().get_block_timestamp() >= auction.start_timestamp,
-            ERR_SALE_IS_NOT_OPENED_YET
+            floor_timestamp > start_timestamp,
+            ERR_CREATE_AUCTION_BAD_END_TIMESTAMP
         );
-
-        let payment = self.call_value().egld_or_single_esdt();
-
         require!(
-            payment.token_identifier == auction.input_token_id,
-            ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH
+            end_timestamp == 0 || end_timestamp > start_timestamp,
+            ERR_CREATE_AUCTION_BAD_END_TIMESTAMP
         );
-
         require!(
-            payment.token_nonce == auction.input_token_nonce,
-            ERR_INVALID_PAYMENT_TOKEN_NONCE_MISMATCH
+            floor_price > 0 && floor_price <= start_price,
+            ERR_CREATE_AUCTION_BAD_FLOOR_PRICE
         );
 
-        require!(
-            &payment.amount > &0 && &payment.amount % &auction.price == 0,
-            ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH
+        let auction_id = self.store_new_auction(
+            input_token_id,
+            input_token_nonce,
+            start_price,
+            start_timestamp,
+            end_timestamp,
+            opt_max_per_wallet.into_option().unwrap_or_default(),
+            self.call_value().single_esdt(),
         );
 
-        let wanted_buy_amount = payment.amount / &auction.price;
+        self.auction_kind(auction_id).set(AuctionKind::Dutch);
+        self.dutch_auctions(auction_id).set(DutchAuction {
+            floor_price,
+            floor_timestamp,
+            price_step_duration,
+        });
+
+        return auction_id;
+    }
+
+    /**
+     * Sell the deposited tokens from `start_price`, the unit price growing
+     * with each item sold along the bonding curve.
+     */
+    #[payable("*")]
+    #[endpoint(createBondingCurveAuction)]
+    fn create_bonding_curve_auction(
+        &self,
+        input_token_id: EgldOrEsdtTokenIdentifier,
+        input_token_nonce: u64,
+        start_price: BigUint,
+        curve: BondingCurve<Self::Api>,
+        start_timestamp: u64,
+        opt_end_timestamp: OptionalValue<u64>,
+        opt_max_per_wallet: OptionalValue<BigUint>,
+    ) -> u64 {
+        let end_timestamp = opt_end_timestamp.into_option().unwrap_or_default();
 
         require!(
-            self.get_remaining_amount(&auction) >= wanted_buy_amount,
-            ERR_NOT_ENOUGHT_ITEMS
+            end_timestamp == 0 || end_timestamp > start_timestamp,
+            ERR_CREATE_AUCTION_BAD_END_TIMESTAMP
         );
 
-        // Send nfts
-        self.send().direct_esdt(
-            &self.blockchain().get_caller(),
-            &auction.output_token_id,
-            auction.output_token_nonce,
-            &wanted_buy_amount,
+        let price_grows = match &curve {
+            BondingCurve::Linear { increment } => *increment > 0,
+            BondingCurve::Exponential { growth_bps } => *growth_bps > 0,
+        };
+
+        require!(price_grows, ERR_CREATE_AUCTION_BAD_CURVE);
+
+        let payment = self.call_value().single_esdt();
+
+        self.require_priceable_curve(&curve, &payment.amount);
+
+        let auction_id = self.store_new_auction(
+            input_token_id,
+            input_token_nonce,
+            start_price,
+            start_timestamp,
+            end_timestamp,
+            opt_max_per_wallet.into_option().unwrap_or_default(),
+            payment,
         );
 
-        auction.current_quantity -= wanted_buy_amount;
+        self.auction_kind(auction_id).set(AuctionKind::BondingCurve);
+        self.bonding_curves(auction_id).set(curve);
 
-        self.auctions(auction_id).set(auction);
+        return auction_id;
     }
 
-    fn get_auction(&self, auction_id: u64) -> Auction<Self::Api> {
-        require!(
-            !self.auctions(auction_id).is_empty(),
-            ERR_INVALID_AUCTION_ID
+    fn store_new_auction(
+        &self,
+        input_token_id: EgldOrEsdtTokenIdentifier,
+        input_token_nonce: u64,
+        price: BigUint,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        max_per_wallet: BigUint,
+        payment: EsdtTokenPayment<Self::Api>,
+    ) -> u64 {
+        if input_token_id.is_egld() {
+            require!(input_token_nonce == 0, ERR_CREATE_AUCTION_BAD_EGLD_NONCE);
+        }
+
+        require!(price > 0, ERR_CREATE_AUCTION_BAD_PRICE);
+
+        let new_auction_id = self.next_auction_id().get();
+        let seller = self.get_seller_of_caller();
+
+        self.create_auction_event(
+            new_auction_id,
+            &payment.token_identifier,
+            payment.token_nonce,
+            &input_token_id,
+            input_token_nonce,
+            &price,
+            &payment.amount,
         );
 
-        return self.auctions(auction_id).get();
-    }
+        self.auctions_by_seller(&seller).insert(new_auction_id);
+        self.auctions_by_output_token(&payment.token_identifier)
+            .insert(new_auction_id);
+        self.auctions_by_payment_token(&input_token_id)
+            .insert(new_auction_id);
 
-    #[view(getAuctionStats)]
-    fn get_auction_stats(&self, auction_id: u64) -> AuctionStats<Self::Api> {
-        let auction = self.get_auction(auction_id);
+        self.save_auction(
+            new_auction_id,
+            Auction {
+                price,
+                start_timestamp,
+                end_timestamp,
+                input_token_id,
+                input_token_nonce,
+                output_token_nonce: payment.token_nonce,
+                output_token_id: payment.token_identifier,
+                max_quantity: payment.amount.clone(),
+                current_quantity: payment.amount,
+                max_per_wallet,
+                status: AuctionStatus::Active,
+                seller,
+            },
+        );
 
-        return AuctionStats {
-            auction,
-            id: auction_id,
-        };
+        self.next_auction_id().set(new_auction_id + 1);
+
+        return new_auction_id;
     }
 
-    #[view(getAllAuctionStats)]
-    fn get_all_auctions_stats(&self) -> ManagedVec<Self::Api, AuctionStats<Self::Api>> {
-        let mut all_auctions = ManagedVec::new();
+    /**
+     * A bundle auction expects every component, in the order of the bundle.
+     * A mystery box accepts many nonces of its collection.
+     */
+    #[endpoint(addTokenToAuction)]
+    #[payable("*")]
+    fn add_token_to_auction(&self, auction_id: u64) {
+        self.require_auction_manager(auction_id);
 
-        for auction_id in STARTING_AUCTION_ID..self.next_auction_id().get() {
-            let auction = self.get_auction_stats(auction_id);
+        let mut auction = self.get_auction(auction_id);
+        self.require_no_bids(auction_id);
 
-            all_auctions.push(auction);
-        }
+        require!(
+            auction.status != AuctionStatus::Closed,
+            ERR_AUCTION_ARCHIVED
+        );
 
-        return all_auctions;
-    }
+        let kind = self.auction_kind(auction_id).get();
 
-    fn get_remaining_amount(&self, auction: &Auction<Self::Api>) -> BigUint<Self::Api> {
-        self.blockchain().get_esdt_balance(
-            &self.blockchain().get_sc_address(),
-            &auction.output_token_id,
-            auction.output_token_nonce,
-        )
+        let quantity = if kind == AuctionKind::Bundle {
+            self.get_deposited_bundle_quantity(auction_id)
+        } else if kind == AuctionKind::MysteryBox {
+            self.fill_mystery_box_pool(auction_id, &self.call_value().all_esdt_transfers())
+        } else {
+            let payment = self.call_value().single_esdt();
+
+            require!(
+                &payment.token_identifier == &auction.output_token_id,
+                ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH
+            );
+
+            require!(
+                &payment.token_nonce == &auction.output_token_nonce,
+                ERR_INVALID_PAYMENT_TOKEN_NONCE_MISMATCH
+            );
+
+            payment.amount
+        };
+
+        if kind == AuctionKind::BondingCurve {
+            self.require_priceable_curve(
+                &self.bonding_curves(auction_id).get(),
+                &(&auction.max_quantity + &quantity),
+            );
+        }
+
+        self.add_token_to_auction_event(auction_id, &quantity);
+
+        auction.max_quantity += &quantity;
+        auction.current_quantity += quantity;
+
+        self.save_auction(auction_id, auction);
+    }
+
+    fn get_deposited_bundle_quantity(&self, auction_id: u64) -> BigUint {
+        let payments = self.call_value().all_esdt_transfers();
+        let components = self.bundles(auction_id).get();
+
+        require!(
+            payments.len() == components.len(),
+            ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH
+        );
+
+        let quantity = &payments.get(0).amount / &components.get(0).amount;
+
+        require!(quantity > 0, ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH);
+
+        for (payment, component) in payments.iter().zip(components.iter()) {
+            require!(
+                payment.token_identifier == component.token_identifier,
+                ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH
+            );
+
+            require!(
+                payment.token_nonce == component.token_nonce,
+                ERR_INVALID_PAYMENT_TOKEN_NONCE_MISMATCH
+            );
+
+            require!(
+                payment.amount == &component.amount * &quantity,
+                ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH
+            );
+        }
+
+        return quantity;
+    }
+
+    #[endpoint(retireTokenFromAuction)]
+    fn retire_token_from_auction(&self, auction_id: u64, amount: &BigUint<Self::Api>) {
+        self.require_auction_manager(auction_id);
+
+        let mut auction = self.get_auction(auction_id);
+        self.require_no_bids(auction_id);
+
+        require!(
+            amount <= &auction.current_quantity,
+            ERR_RETIRING_TOO_MUCH_TOKENS
+        );
+
+        self.send_items(&auction.seller, auction_id, &auction, amount);
+
+        self.retire_token_from_auction_event(auction_id, amount);
+
+        auction.current_quantity -= amount;
+        auction.max_quantity -= amount;
+
+        self.save_auction(auction_id, auction);
+    }
+
+    /**
+     * Sends the seller the proceeds of their auctions.
+     * The proceeds of the marketplace are reserved to the treasurers.
+     */
+    #[endpoint(withdrawBalance)]
+    fn withdraw_balance(&self) {
+        let caller = self.blockchain().get_caller();
+        let seller = self.get_seller_of_caller();
+
+        if seller == self.blockchain().get_owner_address() {
+            self.require_role(Role::Treasurer);
+        }
+
+        for auction_id in self.auctions_by_seller(&seller).iter() {
+            let auction = self.get_auction(auction_id);
+            let amount = self.get_pending_proceeds(auction_id);
+
+            if amount == 0 {
+                continue;
+            }
+
+            self.withdrawn_proceeds(auction_id)
+                .update(|withdrawn| *withdrawn += &amount);
+
+            self.withdraw_balance_event(
+                auction_id,
+                &caller,
+                &auction.input_token_id,
+                auction.input_token_nonce,
+                &amount,
+            );
+
+            self.send().direct(
+                &caller,
+                &auction.input_token_id,
+                auction.input_token_nonce,
+                &amount,
+            );
+        }
+
+        for auction_id in self.auctions_by_seller(&seller).iter() {
+            let mut extra_pending_proceeds = self.extra_pending_proceeds(auction_id);
+
+            loop {
+                let (token_id, token_nonce) = match extra_pending_proceeds.keys().next() {
+                    Some(key) => key,
+                    None => break,
+                };
+                let amount = extra_pending_proceeds
+                    .remove(&(token_id.clone(), token_nonce))
+                    .unwrap_or_default();
+
+                self.withdraw_balance_event(auction_id, &caller, &token_id, token_nonce, &amount);
+                self.send().direct(&caller, &token_id, token_nonce, &amount);
+            }
+        }
+    }
+
+    /**
+     * Accept another payment token for the items of the auction, at its own unit price.
+     * Setting the price of the input token of the auction updates `Auction::price`.
+     * The presale price only applies to the input token of the auction.
+     */
+    #[endpoint(setPaymentTokenPrice)]
+    fn set_payment_token_price(
+        &self,
+        auction_id: u64,
+        token_id: EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        price: BigUint,
+    ) {
+        self.require_auction_manager(auction_id);
+
+        let mut auction = self.get_auction(auction_id);
+        let kind = self.auction_kind(auction_id).get();
+
+        require!(
+            kind != AuctionKind::English
+                && kind != AuctionKind::Dutch
+                && kind != AuctionKind::BondingCurve,
+            ERR_WRONG_AUCTION_KIND
+        );
+        require!(price > 0, ERR_CREATE_AUCTION_BAD_PRICE);
+
+        if token_id.is_egld() {
+            require!(token_nonce == 0, ERR_CREATE_AUCTION_BAD_EGLD_NONCE);
+        }
+
+        self.set_payment_token_price_event(auction_id, &token_id, token_nonce, &price);
+
+        if token_id == auction.input_token_id && token_nonce == auction.input_token_nonce {
+            auction.price = price;
+            self.save_auction(auction_id, auction);
+        } else {
+            self.auctions_by_payment_token(&token_id).insert(auction_id);
+            self.extra_payment_tokens(auction_id)
+                .insert((token_id, token_nonce), price);
+        }
+    }
+
+    #[endpoint(removePaymentToken)]
+    fn remove_payment_token(
+        &self,
+        auction_id: u64,
+        token_id: EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+    ) {
+        self.require_auction_manager(auction_id);
+
+        let auction = self.get_auction(auction_id);
+
+        require!(
+            token_id != auction.input_token_id || token_nonce != auction.input_token_nonce,
+            ERR_PAYMENT_TOKEN_IS_THE_AUCTION_ONE
+        );
+
+        self.remove_payment_token_event(auction_id, &token_id, token_nonce);
+        self.extra_payment_tokens(auction_id)
+            .remove(&(token_id.clone(), token_nonce));
+
+        self.unindex_payment_token(auction_id, &auction, &token_id);
+    }
+
+    /**
+     * Changes the unit price, the start timestamp and the payment token of the auction,
+     * with one event per changed value.
+     * English, dutch and bonding-curve auctions derive their prices from their own settings.
+     * The payment token can't change once items were sold, as the proceeds are kept in it.
+     */
+    #[endpoint(updateAuction)]
+    fn update_auction(
+        &self,
+        auction_id: u64,
+        price: BigUint,
+        start_timestamp: u64,
+        input_token_id: EgldOrEsdtTokenIdentifier,
+        input_token_nonce: u64,
+    ) {
+        self.require_auction_manager(auction_id);
+
+        let mut auction = self.get_auction(auction_id);
+        let kind = self.auction_kind(auction_id).get();
+
+        require!(
+            kind != AuctionKind::English
+                && kind != AuctionKind::Dutch
+                && kind != AuctionKind::BondingCurve,
+            ERR_WRONG_AUCTION_KIND
+        );
+        require!(
+            auction.status != AuctionStatus::Closed,
+            ERR_AUCTION_ARCHIVED
+        );
+        require!(price > 0, ERR_CREATE_AUCTION_BAD_PRICE);
+
+        if input_token_id.is_egld() {
+            require!(input_token_nonce == 0, ERR_CREATE_AUCTION_BAD_EGLD_NONCE);
+        }
+
+        require!(
+            auction.end_timestamp == 0 || auction.end_timestamp > start_timestamp,
+            ERR_CREATE_AUCTION_BAD_END_TIMESTAMP
+        );
+
+        if !self.presales(auction_id).is_empty() {
+            require!(
+                self.presales(auction_id).get().public_start_timestamp > start_timestamp,
+                ERR_PRESALE_BAD_PUBLIC_START_TIMESTAMP
+            );
+        }
+
+        let payment_token_changed = input_token_id != auction.input_token_id
+            || input_token_nonce != auction.input_token_nonce;
+
+        if payment_token_changed {
+            require!(
+                auction.current_quantity == auction.max_quantity,
+                ERR_UPDATE_AUCTION_HAS_SALES
+            );
+        }
+
+        if price != auction.price {
+            self.update_auction_price_event(auction_id, &auction.price, &price);
+            auction.price = price;
+        }
+
+        if start_timestamp != auction.start_timestamp {
+            self.update_auction_start_timestamp_event(
+                auction_id,
+                auction.start_timestamp,
+                start_timestamp,
+            );
+            auction.start_timestamp = start_timestamp;
+        }
+
+        if payment_token_changed {
+            self.update_auction_payment_token_event(
+                auction_id,
+                &auction.input_token_id,
+                auction.input_token_nonce,
+                &input_token_id,
+                input_token_nonce,
+            );
+
+            let previous_token_id = auction.input_token_id.clone();
+
+            auction.input_token_id = input_token_id;
+            auction.input_token_nonce = input_token_nonce;
+
+            // the price of the auction replaces the one of an extra payment token
+            self.extra_payment_tokens(auction_id)
+                .remove(&(auction.input_token_id.clone(), auction.input_token_nonce));
+            self.auctions_by_payment_token(&auction.input_token_id)
+                .insert(auction_id);
+            self.unindex_payment_token(auction_id, &auction, &previous_token_id);
+        }
+
+        self.save_auction(auction_id, auction);
+    }
+
+    /**
+     * Every sale credits each payee with its share of the payment, the rest goes to the seller.
+     * Used by the auctions that don't have their own fee splits and that are listed
+     * by a role holder of the marketplace, the other sellers only pay the platform fee.
+     */
+    #[endpoint(setDefaultFeeSplits)]
+    fn set_default_fee_splits(
+        &self,
+        fee_splits: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>,
+    ) {
+        self.require_role(Role::Treasurer);
+
+        let fee_splits = self.build_fee_splits(fee_splits);
+
+        self.set_default_fee_splits_event(&fee_splits);
+        self.default_fee_splits().set(fee_splits);
+    }
+
+    /**
+     * Every sale of every seller credits the payee with its share of the payment,
+     * after the royalties and before the fee splits. A share of 0 removes the platform fee.
+     */
+    #[endpoint(setPlatformFee)]
+    fn set_platform_fee(&self, payee: ManagedAddress, bps: u64) {
+        self.require_role(Role::Treasurer);
+
+        require!(bps <= MAX_PLATFORM_FEE_BPS, ERR_PLATFORM_FEE_TOO_HIGH);
+
+        self.set_platform_fee_event(&payee, bps);
+
+        if bps == 0 {
+            self.platform_fee().clear();
+        } else {
+            self.platform_fee().set(FeeShare { payee, bps });
+        }
+    }
+
+    /**
+     * Only the seller shares their own proceeds.
+     * An empty list makes the auction use the default fee splits again.
+     */
+    #[endpoint(setAuctionFeeSplits)]
+    fn set_auction_fee_splits(
+        &self,
+        auction_id: u64,
+        fee_splits: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>,
+    ) {
+        require!(
+            !self.auctions(auction_id).is_empty(),
+            ERR_INVALID_AUCTION_ID
+        );
+
+        require!(
+            self.blockchain().get_caller() == self.auctions(auction_id).get().seller,
+            ERR_NOT_AUCTION_SELLER
+        );
+
+        let fee_splits = self.build_fee_splits(fee_splits);
+
+        self.set_auction_fee_splits_event(auction_id, &fee_splits);
+        self.auction_fee_splits(auction_id).set(fee_splits);
+    }
+
+    /**
+     * When enabled, the creator of the output token accrues its royalties on each sale,
+     * before the fee splits and the seller proceeds.
+     * Mystery boxes and bundles sell several tokens per item, each with its own royalties.
+     */
+    #[endpoint(setHonorRoyalties)]
+    fn set_honor_royalties(&self, auction_id: u64, honor_royalties: bool) {
+        self.require_auction_manager(auction_id);
+
+        let kind = self.auction_kind(auction_id).get();
+
+        require!(
+            kind != AuctionKind::MysteryBox && kind != AuctionKind::Bundle,
+            ERR_WRONG_AUCTION_KIND
+        );
+
+        self.set_honor_royalties_event(auction_id, honor_royalties);
+        self.honor_royalties(auction_id).set(honor_royalties);
+    }
+
+    /** Sends to the caller all the fees and royalties they have accrued */
+    #[endpoint(claimFees)]
+    fn claim_fees(&self) {
+        let caller = self.blockchain().get_caller();
+        let mut accrued_fee_tokens = self.accrued_fee_tokens(&caller);
+
+        for (token_id, token_nonce) in accrued_fee_tokens.iter() {
+            let amount = self.accrued_fees(&caller, &token_id, token_nonce).take();
+
+            self.claim_fees_event(&caller, &token_id, token_nonce, &amount);
+            self.send().direct(&caller, &token_id, token_nonce, &amount);
+        }
+
+        accrued_fee_tokens.clear();
+    }
+
+    #[endpoint(hideAuction)]
+    fn hide_auction(&self, auction_id: u64) {
+        self.require_auction_manager(auction_id);
+
+        let mut auction = self.get_auction(auction_id);
+
+        require!(
+            auction.status != AuctionStatus::Closed,
+            ERR_AUCTION_ARCHIVED
+        );
+
+        self.retire_token_from_auction(auction_id, &auction.current_quantity);
+
+        auction = self.get_auction(auction_id);
+        auction.status = AuctionStatus::Hidden;
+
+        self.save_auction(auction_id, auction);
+        self.hide_auction_event(auction_id);
+    }
+
+    /**
+     * Closes an auction for good once its items are gone and its proceeds withdrawn.
+     * Archived auctions are left out of the listing views.
+     */
+    #[endpoint(archiveAuction)]
+    fn archive_auction(&self, auction_id: u64) {
+        self.require_auction_manager(auction_id);
+
+        let mut auction = self.get_auction(auction_id);
+
+        require!(
+            auction.status != AuctionStatus::Closed,
+            ERR_AUCTION_ARCHIVED
+        );
+        require!(auction.current_quantity == 0, ERR_ARCHIVE_AUCTION_HAS_ITEMS);
+        require!(
+            self.get_pending_proceeds(auction_id) == 0
+                && self.extra_pending_proceeds(auction_id).is_empty(),
+            ERR_ARCHIVE_AUCTION_HAS_PROCEEDS
+        );
+
+        auction.status = AuctionStatus::Closed;
+
+        self.save_auction(auction_id, auction);
+        self.archive_auction_event(auction_id);
+    }
+
+    #[endpoint]
+    fn pause(&self) {
+        self.require_role(Role::Pauser);
+
+        self.pause_event();
+        self.paused().set(true);
+    }
+
+    #[endpoint]
+    fn unpause(&self) {
+        self.require_role(Role::Pauser);
+
+        self.unpause_event();
+        self.paused().clear();
+    }
+
+    /** The items of a paused auction can't be bought, it can still be edited and its items retired */
+    #[endpoint(pauseAuction)]
+    fn pause_auction(&self, auction_id: u64) {
+        self.require_role(Role::Pauser);
+
+        let mut auction = self.get_auction(auction_id);
+
+        require!(
+            auction.status == AuctionStatus::Active,
+            ERR_AUCTION_NOT_ACTIVE
+        );
+
+        auction.status = AuctionStatus::Paused;
+
+        self.save_auction(auction_id, auction);
+        self.pause_auction_event(auction_id);
+    }
+
+    #[endpoint(unpauseAuction)]
+    fn unpause_auction(&self, auction_id: u64) {
+        self.require_role(Role::Pauser);
+
+        let mut auction = self.get_auction(auction_id);
+
+        require!(
+            auction.status == AuctionStatus::Paused,
+            ERR_AUCTION_NOT_PAUSED
+        );
+
+        auction.status = AuctionStatus::Active;
+
+        self.save_auction(auction_id, auction);
+        self.unpause_auction_event(auction_id);
+    }
+
+    /**
+     * Between `start_timestamp` and `public_start_timestamp`, only the allowlisted addresses can buy.
+     * During this phase, `price` and `max_per_wallet` replace the ones of the auction, unless set to 0.
+     */
+    #[endpoint(setPresale)]
+    fn set_presale(
+        &self,
+        auction_id: u64,
+        public_start_timestamp: u64,
+        price: BigUint,
+        max_per_wallet: BigUint,
+    ) {
+        self.require_auction_manager(auction_id);
+
+        let auction = self.get_auction(auction_id);
+        let kind = self.auction_kind(auction_id).get();
+
+        // the price of a bonding curve can't be replaced, only its buyers restricted
+        require!(
+            kind != AuctionKind::English && (kind != AuctionKind::BondingCurve || price == 0),
+            ERR_WRONG_AUCTION_KIND
+        );
+
+        require!(
+            public_start_timestamp > auction.start_timestamp,
+            ERR_PRESALE_BAD_PUBLIC_START_TIMESTAMP
+        );
+
+        self.set_presale_event(auction_id, public_start_timestamp, &price, &max_per_wallet);
+
+        self.presales(auction_id).set(Presale {
+            public_start_timestamp,
+            price,
+            max_per_wallet,
+        });
+    }
+
+    /** Opens the sale to everyone from `start_timestamp`, the allowlist is kept */
+    #[endpoint(clearPresale)]
+    fn clear_presale(&self, auction_id: u64) {
+        self.require_auction_manager(auction_id);
+
+        self.clear_presale_event(auction_id);
+        self.presales(auction_id).clear();
+    }
+
+    /**
+     * Changes the price in the input token over time, each tier being a `(from_timestamp, price)` pair.
+     * The extra payment tokens keep their own price. An empty list removes the schedule.
+     */
+    #[endpoint(setPriceSchedule)]
+    fn set_price_schedule(
+        &self,
+        auction_id: u64,
+        tiers: MultiValueEncoded<MultiValue2<u64, BigUint>>,
+    ) {
+        self.require_auction_manager(auction_id);
+
+        let kind = self.auction_kind(auction_id).get();
+
+        require!(
+            kind != AuctionKind::English
+                && kind != AuctionKind::Dutch
+                && kind != AuctionKind::BondingCurve,
+            ERR_WRONG_AUCTION_KIND
+        );
+
+        let mut schedule = ManagedVec::new();
+        let mut previous_timestamp = 0u64;
+
+        for tier in tiers {
+            let (from_timestamp, price) = tier.into_tuple();
+
+            require!(
+                price > 0 && (schedule.is_empty() || from_timestamp > previous_timestamp),
+                ERR_PRICE_SCHEDULE_BAD_TIERS
+            );
+            previous_timestamp = from_timestamp;
+
+            schedule.push(PriceTier {
+                from_timestamp,
+                price,
+            });
+        }
+
+        self.set_price_schedule_event(auction_id, &schedule);
+        self.price_schedules(auction_id).set(schedule);
+    }
+
+    #[endpoint(addToAllowlist)]
+    fn add_to_allowlist(&self, auction_id: u64, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_auction_manager(auction_id);
+
+        let mut allowlist = self.allowlist(auction_id);
+
+        for address in addresses {
+            self.add_to_allowlist_event(auction_id, &address);
+            allowlist.insert(address);
+        }
+    }
+
+    #[endpoint(removeFromAllowlist)]
+    fn remove_from_allowlist(&self, auction_id: u64, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_auction_manager(auction_id);
+
+        let mut allowlist = self.allowlist(auction_id);
+
+        for address in addresses {
+            self.remove_from_allowlist_event(auction_id, &address);
+            allowlist.swap_remove(&address);
+        }
+    }
+
+    /**
+     * During the presale, the addresses of the Merkle tree can buy too, by giving a proof to `buy`.
+     * See `merkle` to build the tree and the proofs.
+     */
+    #[endpoint(setAllowlistMerkleRoot)]
+    fn set_allowlist_merkle_root(
+        &self,
+        auction_id: u64,
+        merkle_root: ManagedByteArray<Self::Api, 32>,
+    ) {
+        self.require_auction_manager(auction_id);
+
+        self.set_allowlist_merkle_root_event(auction_id, &merkle_root);
+        self.allowlist_merkle_root(auction_id).set(merkle_root);
+    }
+
+    #[endpoint(clearAllowlistMerkleRoot)]
+    fn clear_allowlist_merkle_root(&self, auction_id: u64) {
+        self.require_auction_manager(auction_id);
+
+        self.clear_allowlist_merkle_root_event(auction_id);
+        self.allowlist_merkle_root(auction_id).clear();
+    }
+
+    /**
+     * Without `opt_quantity`, or with 0, the quantity is `payment / price`.
+     * Otherwise, `quantity * price` is charged and the excess of the payment is refunded.
+     * `opt_proof` is only needed during the presale, by the addresses of the allowlist Merkle tree.
+     */
+    #[payable("*")]
+    #[endpoint]
+    fn buy(
+        &self,
+        auction_id: u64,
+        opt_quantity: OptionalValue<BigUint>,
+        opt_proof: OptionalValue<AllowlistProof<Self::Api>>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().egld_or_single_esdt();
+        let mut items = ManagedVec::new();
+
+        let cost = self
+            .purchase(
+                auction_id,
+                &caller,
+                &payment,
+                opt_quantity.into_option(),
+                opt_proof.into_option(),
+                &mut items,
+            )
+            .unwrap_or_else(|err| sc_panic!(err));
+        let change = &payment.amount - &cost;
+
+        if change > 0 {
+            self.send().direct(
+                &caller,
+                &payment.token_identifier,
+                payment.token_nonce,
+                &change,
+            );
+        }
+
+        // Send nfts
+        self.send_payments(&caller, &items);
+    }
+
+    /**
+     * Buys `quantity` items of each auction with one multi-ESDT payment.
+     * Each auction is paid with the first payment it accepts, the items and the remainder of the payments
+     * are sent back with one transfer. The checkout fails as a whole if one of the purchases fails.
+     */
+    #[payable("*")]
+    #[endpoint(buyMany)]
+    fn buy_many(&self, purchases: MultiValueEncoded<MultiValue2<u64, BigUint>>) {
+        let caller = self.blockchain().get_caller();
+        let mut funds = self.call_value().all_esdt_transfers();
+        let mut costs: ManagedVec<BigUint> = ManagedVec::new();
+        let mut items = ManagedVec::new();
+
+        for _ in funds.iter() {
+            costs.push(BigUint::zero());
+        }
+
+        // the whole checkout is priced before anything is settled
+        for purchase in purchases.clone().into_iter() {
+            let (auction_id, quantity) = purchase.into_tuple();
+
+            require!(quantity > 0, ERR_BUY_MANY_BAD_QUANTITY);
+
+            let index = self.find_accepted_payment(auction_id, &funds);
+            let fund = funds.get(index);
+
+            *costs.get_mut(index) += self.get_purchase_cost(
+                auction_id,
+                &EgldOrEsdtTokenIdentifier::esdt(fund.token_identifier),
+                fund.token_nonce,
+                &quantity,
+            );
+        }
+
+        for (fund, cost) in funds.iter().zip(costs.iter()) {
+            require!(
+                *cost <= fund.amount,
+                ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH
+            );
+        }
+
+        for purchase in purchases.into_iter() {
+            let (auction_id, quantity) = purchase.into_tuple();
+
+            let index = self.find_accepted_payment(auction_id, &funds);
+            let fund = funds.get(index);
+            let payment = EgldOrEsdtTokenPayment::new(
+                EgldOrEsdtTokenIdentifier::esdt(fund.token_identifier),
+                fund.token_nonce,
+                fund.amount,
+            );
+
+            funds.get_mut(index).amount -= self
+                .purchase(
+                    auction_id,
+                    &caller,
+                    &payment,
+                    Some(quantity),
+                    None,
+                    &mut items,
+                )
+                .unwrap_or_else(|err| sc_panic!(err));
+        }
+
+        for fund in funds.iter() {
+            if fund.amount > 0 {
+                items.push(fund);
+            }
+        }
+
+        self.send_payments(&caller, &items);
+    }
+
+    /**
+     * Buys `quantity` items of each auction with one EGLD payment.
+     * The items are sent back with one multi transfer and the remaining EGLD is refunded.
+     * The errors are prefixed by the id of the auction that failed.
+     */
+    #[payable("EGLD")]
+    #[endpoint(buyCart)]
+    fn buy_cart(&self, cart: MultiValueEncoded<MultiValue2<u64, BigUint>>) {
+        let caller = self.blockchain().get_caller();
+        let mut funds = self.call_value().egld_value();
+        let mut items = ManagedVec::new();
+
+        for cart_item in cart.into_iter() {
+            let (auction_id, quantity) = cart_item.into_tuple();
+
+            let payment =
+                EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::egld(), 0, funds.clone());
+            let result = if quantity == 0 {
+                core::result::Result::Err(ERR_CART_BAD_QUANTITY)
+            } else {
+                self.purchase(
+                    auction_id,
+                    &caller,
+                    &payment,
+                    Some(quantity),
+                    None,
+                    &mut items,
+                )
+            };
+
+            funds -= result.unwrap_or_else(|err| {
+                sc_panic!("Auction {}: {}", auction_id, ManagedBuffer::from(err))
+            });
+        }
+
+        if funds > 0 {
+            self.send().direct_egld(&caller, &funds);
+        }
+
+        self.send_payments(&caller, &items);
+    }
+
+    /** Index of the first payment accepted by the auction */
+    fn find_accepted_payment(
+        &self,
+        auction_id: u64,
+        funds: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+    ) -> usize {
+        let auction = self.get_auction(auction_id);
+        let extra_payment_tokens = self.extra_payment_tokens(auction_id);
+
+        for (index, fund) in funds.iter().enumerate() {
+            let token_id = EgldOrEsdtTokenIdentifier::esdt(fund.token_identifier);
+
+            if fund.amount > 0
+                && ((token_id == auction.input_token_id
+                    && fund.token_nonce == auction.input_token_nonce)
+                    || extra_payment_tokens.contains_key(&(token_id, fund.token_nonce)))
+            {
+                return index;
+            }
+        }
+
+        sc_panic!(ERR_BUY_MANY_MISSING_PAYMENT);
+    }
+
+    /**
+     * Sells `opt_quantity` items of the auction to the buyer,
+     * or as many items as `payment` pays for when it is `None`, and returns their cost.
+     * The items are added to `items`, the caller must send them and refund the change.
+     * Every check runs before the first write, so the callers can report the error as they see fit.
+     */
+    fn purchase(
+        &self,
+        auction_id: u64,
+        buyer: &ManagedAddress,
+        payment: &EgldOrEsdtTokenPayment<Self::Api>,
+        opt_quantity: Option<BigUint>,
+        opt_proof: Option<AllowlistProof<Self::Api>>,
+        items: &mut ManagedVec<EsdtTokenPayment<Self::Api>>,
+    ) -> Result<BigUint, &'static str> {
+        if self.auctions(auction_id).is_empty() {
+            return core::result::Result::Err(ERR_INVALID_AUCTION_ID);
+        }
+
+        let mut auction = self.auctions(auction_id).get();
+
+        if let Some(err) = self.get_auction_status_error(&auction) {
+            return core::result::Result::Err(err);
+        }
+
+        let kind = self.auction_kind(auction_id).get();
+
+        if kind == AuctionKind::English {
+            return core::result::Result::Err(ERR_WRONG_AUCTION_KIND);
+        }
+
+        let now = self.blockchain().get_block_timestamp();
+
+        if now < auction.start_timestamp {
+            return core::result::Result::Err(ERR_SALE_IS_NOT_OPENED_YET);
+        }
+
+        if auction.end_timestamp != 0 && now >= auction.end_timestamp {
+            return core::result::Result::Err(ERR_SALE_IS_CLOSED);
+        }
+
+        let opt_presale = self.get_running_presale(auction_id, now);
+
+        let mut allowance = BigUint::zero();
+
+        if opt_presale.is_some() {
+            match opt_proof {
+                Some(proof) => {
+                    if !self.verify_allowlist_proof(auction_id, buyer, &proof) {
+                        return core::result::Result::Err(ERR_INVALID_ALLOWLIST_PROOF);
+                    }
+
+                    allowance = BigUint::from(proof.allowance);
+                }
+                None => {
+                    if !self.allowlist(auction_id).contains(buyer) {
+                        return core::result::Result::Err(ERR_NOT_ALLOWLISTED);
+                    }
+                }
+            }
+        }
+
+        let quantity = opt_quantity.unwrap_or_default();
+        let remaining_amount = self.get_remaining_amount(&auction);
+
+        // a sold out bonding curve has no price for its next item
+        if remaining_amount == 0 || remaining_amount < quantity {
+            return core::result::Result::Err(ERR_NOT_ENOUGHT_ITEMS);
+        }
+
+        let unit_price = self.get_unit_price(
+            auction_id,
+            &auction,
+            &payment.token_identifier,
+            payment.token_nonce,
+            &opt_presale,
+        )?;
+
+        let wanted_buy_amount = if quantity > 0 {
+            quantity
+        } else if kind == AuctionKind::BondingCurve {
+            // each item costs more than the previous one,
+            // so we sell as many items as the payment covers and give back the change
+            self.get_affordable_curve_quantity(auction_id, &auction, &payment.amount)
+        } else {
+            // the price of a dutch auction moves between the signature and the execution,
+            // so we sell as many items as possible and give back the change
+            if kind != AuctionKind::Dutch && &payment.amount % &unit_price != 0 {
+                return core::result::Result::Err(ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH);
+            }
+
+            &payment.amount / &unit_price
+        };
+
+        if wanted_buy_amount == 0 {
+            return core::result::Result::Err(ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH);
+        }
+
+        if remaining_amount < wanted_buy_amount {
+            return core::result::Result::Err(ERR_NOT_ENOUGHT_ITEMS);
+        }
+
+        let cost = self.get_cost(auction_id, &auction, &unit_price, &wanted_buy_amount);
+
+        if payment.amount < cost {
+            return core::result::Result::Err(ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH);
+        }
+
+        let max_per_wallet = if allowance > 0 {
+            allowance
+        } else {
+            self.get_max_per_wallet(&auction, &opt_presale)
+        };
+        let purchased_quantity =
+            self.purchased_quantity(auction_id, buyer).get() + &wanted_buy_amount;
+
+        if max_per_wallet > 0 && purchased_quantity > max_per_wallet {
+            return core::result::Result::Err(ERR_MAX_PER_WALLET_EXCEEDED);
+        }
+
+        self.purchased_quantity(auction_id, buyer)
+            .set(purchased_quantity);
+
+        // before sending the items, the royalties are read from the ones held by the contract
+        self.distribute_proceeds(
+            auction_id,
+            &auction,
+            &payment.token_identifier,
+            payment.token_nonce,
+            &cost,
+        );
+
+        self.collect_items(auction_id, &auction, &wanted_buy_amount, items);
+
+        self.buy_event(
+            auction_id,
+            buyer,
+            &auction.output_token_id,
+            auction.output_token_nonce,
+            &wanted_buy_amount,
+            &cost,
+            &payment.token_identifier,
+            payment.token_nonce,
+        );
+
+        auction.current_quantity -= wanted_buy_amount;
+
+        self.save_auction(auction_id, auction);
+
+        return core::result::Result::Ok(cost);
+    }
+
+    /** Unit price of the auction in the payment token, or the error if the token is not accepted */
+    fn get_unit_price(
+        &self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        opt_presale: &Option<Presale<Self::Api>>,
+    ) -> Result<BigUint, &'static str> {
+        if token_id == &auction.input_token_id && token_nonce == auction.input_token_nonce {
+            return core::result::Result::Ok(match opt_presale {
+                Some(presale) if presale.price > 0 => presale.price.clone(),
+                _ => self.get_current_price(auction_id),
+            });
+        }
+
+        if let Some(price) = self
+            .extra_payment_tokens(auction_id)
+            .get(&(token_id.clone(), token_nonce))
+        {
+            return core::result::Result::Ok(price);
+        }
+
+        if token_id != &auction.input_token_id {
+            return core::result::Result::Err(ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH);
+        }
+
+        return core::result::Result::Err(ERR_INVALID_PAYMENT_TOKEN_NONCE_MISMATCH);
+    }
+
+    /** Cost of the next `quantity` items of the auction in the payment token */
+    fn get_purchase_cost(
+        &self,
+        auction_id: u64,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        quantity: &BigUint,
+    ) -> BigUint {
+        let auction = self.get_auction(auction_id);
+
+        require!(quantity <= &auction.current_quantity, ERR_NOT_ENOUGHT_ITEMS);
+
+        let opt_presale =
+            self.get_running_presale(auction_id, self.blockchain().get_block_timestamp());
+        let unit_price = self
+            .get_unit_price(auction_id, &auction, token_id, token_nonce, &opt_presale)
+            .unwrap_or_else(|err| sc_panic!(err));
+
+        return self.get_cost(auction_id, &auction, &unit_price, quantity);
+    }
+
+    /** Price of `quantity` items at `unit_price`, or along the bonding curve of the auction */
+    fn get_cost(
+        &self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        unit_price: &BigUint,
+        quantity: &BigUint,
+    ) -> BigUint {
+        if self.auction_kind(auction_id).get() != AuctionKind::BondingCurve {
+            return quantity * unit_price;
+        }
+
+        return self.get_curve_cost(auction, &self.bonding_curves(auction_id).get(), quantity);
+    }
+
+    /** Sum of the unit prices of the next `quantity` items of the curve, rounded down once */
+    fn get_curve_cost(
+        &self,
+        auction: &Auction<Self::Api>,
+        curve: &BondingCurve<Self::Api>,
+        quantity: &BigUint,
+    ) -> BigUint {
+        if *quantity == 0 {
+            return BigUint::zero();
+        }
+
+        let sold = &auction.max_quantity - &auction.current_quantity;
+
+        match curve {
+            BondingCurve::Linear { increment } => {
+                // sum of n for n in sold..sold + quantity
+                let steps = quantity
+                    * &(&sold * &BigUint::from(2u64) + quantity - BigUint::from(1u64))
+                    / BigUint::from(2u64);
+
+                return quantity * &auction.price + increment * &steps;
+            }
+            BondingCurve::Exponential { growth_bps } => {
+                let first = sold.to_u64().unwrap_or(u64::MAX);
+                let count = quantity.to_u64().unwrap_or(u64::MAX);
+
+                require!(
+                    first.saturating_add(count) <= MAX_EXPONENTIAL_CURVE_QUANTITY,
+                    ERR_BONDING_CURVE_TOO_LONG
+                );
+
+                // geometric sum of price * ratio^n for n in sold..sold + quantity,
+                // with ratio = (MAX_BPS + growth_bps) / MAX_BPS
+                let first = first as u32;
+                let count = count as u32;
+                let base = BigUint::from(MAX_BPS);
+                let ratio = BigUint::from(MAX_BPS + growth_bps);
+                let numerator = &auction.price
+                    * &(ratio.pow(first + count) - ratio.pow(first) * base.pow(count));
+                let denominator = BigUint::from(*growth_bps) * base.pow(first + count - 1);
+
+                return numerator / denominator;
+            }
+        }
+    }
+
+    /** The cost of an exponential curve raises its ratio to the number of items sold */
+    fn require_priceable_curve(&self, curve: &BondingCurve<Self::Api>, max_quantity: &BigUint) {
+        if let BondingCurve::Exponential { .. } = curve {
+            require!(
+                *max_quantity <= MAX_EXPONENTIAL_CURVE_QUANTITY,
+                ERR_BONDING_CURVE_TOO_LONG
+            );
+        }
+    }
+
+    /** Largest quantity of items whose price along the curve fits in `amount` */
+    fn get_affordable_curve_quantity(
+        &self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        amount: &BigUint,
+    ) -> BigUint {
+        let curve = self.bonding_curves(auction_id).get();
+        let mut low = BigUint::zero();
+        let mut high = auction.current_quantity.clone();
+
+        while low < high {
+            let middle = (&low + &high + BigUint::from(1u64)) / BigUint::from(2u64);
+
+            if &self.get_curve_cost(auction, &curve, &middle) <= amount {
+                low = middle;
+            } else {
+                high = middle - BigUint::from(1u64);
+            }
+        }
+
+        return low;
+    }
+
+    /**
+     * Outbid the current highest bidder of an english auction.
+     * The previous highest bid is refunded in the same transaction.
+     */
+    #[payable("*")]
+    #[endpoint]
+    fn bid(&self, auction_id: u64) {
+        let auction = self.get_auction(auction_id);
+        let mut english_auction = self.get_english_auction(auction_id);
+        let now = self.blockchain().get_block_timestamp();
+
+        if let Some(err) = self.get_auction_status_error(&auction) {
+            sc_panic!(err);
+        }
+
+        require!(now >= auction.start_timestamp, ERR_SALE_IS_NOT_OPENED_YET);
+        require!(now < auction.end_timestamp, ERR_BIDDING_IS_OVER);
+        require!(auction.current_quantity > 0, ERR_NOT_ENOUGHT_ITEMS);
+
+        let payment = self.call_value().egld_or_single_esdt();
+
+        require!(
+            payment.token_identifier == auction.input_token_id,
+            ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH
+        );
+
+        require!(
+            payment.token_nonce == auction.input_token_nonce,
+            ERR_INVALID_PAYMENT_TOKEN_NONCE_MISMATCH
+        );
+
+        require!(
+            payment.amount >= self.get_minimum_bid(auction_id),
+            ERR_BID_TOO_LOW
+        );
+
+        if english_auction.highest_bid > 0 {
+            self.send().direct(
+                &english_auction.highest_bidder,
+                &auction.input_token_id,
+                auction.input_token_nonce,
+                &english_auction.highest_bid,
+            );
+        }
+
+        let caller = self.blockchain().get_caller();
+
+        self.bid_event(auction_id, &caller, &payment.amount);
+
+        english_auction.highest_bid = payment.amount;
+        english_auction.highest_bidder = caller;
+
+        self.english_auctions(auction_id).set(english_auction);
+    }
+
+    /**
+     * Settle an ended english auction.
+     * The lot goes to the highest bidder, or back to the seller if nobody has bid.
+     */
+    #[endpoint]
+    fn claim(&self, auction_id: u64) {
+        let mut auction = self.get_auction(auction_id);
+        let mut english_auction = self.get_english_auction(auction_id);
+
+        require!(
+            self.blockchain().get_block_timestamp() >= auction.end_timestamp,
+            ERR_BIDDING_IS_NOT_OVER
+        );
+        require!(!english_auction.claimed, ERR_AUCTION_ALREADY_CLAIMED);
+
+        let has_winner = english_auction.highest_bid > 0;
+        let receiver = if has_winner {
+            english_auction.highest_bidder.clone()
+        } else {
+            auction.seller.clone()
+        };
+
+        if has_winner {
+            self.distribute_proceeds(
+                auction_id,
+                &auction,
+                &auction.input_token_id,
+                auction.input_token_nonce,
+                &english_auction.highest_bid,
+            );
+        } else {
+            auction.max_quantity -= &auction.current_quantity;
+        }
+
+        if auction.current_quantity > 0 {
+            self.send().direct_esdt(
+                &receiver,
+                &auction.output_token_id,
+                auction.output_token_nonce,
+                &auction.current_quantity,
+            );
+        }
+
+        self.claim_event(
+            auction_id,
+            &receiver,
+            &auction.current_quantity,
+            &english_auction.highest_bid,
+        );
+
+        auction.current_quantity = BigUint::zero();
+        english_auction.claimed = true;
+
+        self.save_auction(auction_id, auction);
+        self.english_auctions(auction_id).set(english_auction);
+    }
+
+    fn build_fee_splits(
+        &self,
+        fee_splits: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>,
+    ) -> ManagedVec<FeeShare<Self::Api>> {
+        let mut shares = ManagedVec::new();
+        let mut total_bps = 0u64;
+
+        for fee_split in fee_splits {
+            let (payee, bps) = fee_split.into_tuple();
+
+            require!(bps > 0, ERR_FEE_SPLITS_BAD_SHARES);
+            total_bps += bps;
+
+            shares.push(FeeShare { payee, bps });
+        }
+
+        require!(total_bps <= MAX_BPS, ERR_FEE_SPLITS_BAD_SHARES);
+
+        return shares;
+    }
+
+    /**
+     * Credits the creator royalties, the platform fee, the fee splits,
+     * then the seller proceeds of a sale of `amount`.
+     * Must be called while the contract still holds the sold items.
+     */
+    fn distribute_proceeds(
+        &self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amount: &BigUint,
+    ) {
+        let mut remaining = amount.clone();
+
+        if self.honor_royalties(auction_id).get() && auction.output_token_nonce != 0 {
+            let token_data = self.blockchain().get_esdt_token_data(
+                &self.blockchain().get_sc_address(),
+                &auction.output_token_id,
+                auction.output_token_nonce,
+            );
+            let royalties = amount * &token_data.royalties / BigUint::from(MAX_BPS);
+
+            if royalties > 0 {
+                remaining -= &royalties;
+
+                self.royalties_event(auction_id, &token_data.creator, &royalties);
+                self.credit_fee(&token_data.creator, token_id, token_nonce, &royalties);
+            }
+        }
+
+        if !self.platform_fee().is_empty() {
+            let platform_fee = self.platform_fee().get();
+            let fee = &remaining * &BigUint::from(platform_fee.bps) / BigUint::from(MAX_BPS);
+
+            if fee > 0 {
+                remaining -= &fee;
+
+                self.credit_fee(&platform_fee.payee, token_id, token_nonce, &fee);
+            }
+        }
+
+        let mut seller_share = remaining.clone();
+
+        for fee_share in self.get_fee_splits(auction_id).iter() {
+            let fee = &remaining * &BigUint::from(fee_share.bps) / BigUint::from(MAX_BPS);
+
+            if fee == 0 {
+                continue;
+            }
+
+            seller_share -= &fee;
+
+            self.credit_fee(&fee_share.payee, token_id, token_nonce, &fee);
+        }
+
+        self.seller_earnings(&auction.seller, token_id, token_nonce)
+            .update(|earnings| *earnings += &seller_share);
+        self.seller_earning_tokens(&auction.seller)
+            .insert((token_id.clone(), token_nonce));
+
+        if token_id == &auction.input_token_id && token_nonce == auction.input_token_nonce {
+            self.collected_proceeds(auction_id)
+                .update(|collected| *collected += seller_share);
+        } else {
+            let mut extra_pending_proceeds = self.extra_pending_proceeds(auction_id);
+            let key = (token_id.clone(), token_nonce);
+            let pending = extra_pending_proceeds.get(&key).unwrap_or_default();
+
+            extra_pending_proceeds.insert(key, pending + seller_share);
+        }
+    }
+
+    /** Sends `quantity` items of the auction, with one multi transfer for a bundle or a mystery box */
+    fn send_items(
+        &self,
+        receiver: &ManagedAddress,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        quantity: &BigUint,
+    ) {
+        let mut items = ManagedVec::new();
+
+        self.collect_items(auction_id, auction, quantity, &mut items);
+        self.send_payments(receiver, &items);
+    }
+
+    /** Adds `quantity` items of the auction to `items`, the components of a bundle are added one by one */
+    fn collect_items(
+        &self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        quantity: &BigUint,
+        items: &mut ManagedVec<EsdtTokenPayment<Self::Api>>,
+    ) {
+        match self.auction_kind(auction_id).get() {
+            AuctionKind::Bundle => {
+                for component in self.bundles(auction_id).get().iter() {
+                    items.push(EsdtTokenPayment::new(
+                        component.token_identifier.clone(),
+                        component.token_nonce,
+                        &component.amount * quantity,
+                    ));
+                }
+            }
+            AuctionKind::MysteryBox => {
+                let mut pool = self.mystery_box_pool(auction_id);
+                let mut rand_source: RandomnessSource<Self::Api> = RandomnessSource::new();
+                let mut left = auction.current_quantity.to_u64().unwrap_or_default();
+                let draws = quantity.to_u64().unwrap_or_default();
+
+                // drawn without replacement, each nonce in proportion of its items left,
+                // the VecMapper indexes start at 1
+                for _ in 0..draws {
+                    let mut ticket = rand_source.next_u64_in_range(0, left);
+                    let mut index = 1;
+                    let (mut nonce, mut remaining) = pool.get(index);
+
+                    while ticket >= remaining {
+                        ticket -= remaining;
+                        index += 1;
+                        (nonce, remaining) = pool.get(index);
+                    }
+
+                    if remaining == 1 {
+                        pool.swap_remove(index);
+                    } else {
+                        pool.set(index, &(nonce, remaining - 1));
+                    }
+
+                    left -= 1;
+                    items.push(EsdtTokenPayment::new(
+                        auction.output_token_id.clone(),
+                        nonce,
+                        BigUint::from(1u64),
+                    ));
+                }
+            }
+            _ => items.push(EsdtTokenPayment::new(
+                auction.output_token_id.clone(),
+                auction.output_token_nonce,
+                quantity.clone(),
+            )),
+        }
+    }
+
+    /** Sends every payment with one transfer */
+    fn send_payments(
+        &self,
+        receiver: &ManagedAddress,
+        payments: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+    ) {
+        if payments.len() == 1 {
+            let payment = payments.get(0);
+
+            self.send().direct_esdt(
+                receiver,
+                &payment.token_identifier,
+                payment.token_nonce,
+                &payment.amount,
+            );
+        } else if !payments.is_empty() {
+            self.send().direct_multi(receiver, payments);
+        }
+    }
+
+    fn credit_fee(
+        &self,
+        payee: &ManagedAddress,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amount: &BigUint,
+    ) {
+        self.accrued_fees(payee, token_id, token_nonce)
+            .update(|accrued| *accrued += amount);
+        self.accrued_fee_tokens(payee)
+            .insert((token_id.clone(), token_nonce));
+    }
+
+    /** Removes the auction from the index of the token once no nonce of it is accepted */
+    fn unindex_payment_token(
+        &self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) {
+        let still_accepted = token_id == &auction.input_token_id
+            || self
+                .extra_payment_tokens(auction_id)
+                .keys()
+                .any(|(accepted_token_id, _)| &accepted_token_id == token_id);
+
+        if !still_accepted {
+            self.auctions_by_payment_token(token_id).remove(&auction_id);
+        }
+    }
+
+    /**
+     * The seller of the auction can edit it.
+     * A listing manager can too when the auction is sold by the marketplace.
+     */
+    fn require_auction_manager(&self, auction_id: u64) {
+        require!(
+            !self.auctions(auction_id).is_empty(),
+            ERR_INVALID_AUCTION_ID
+        );
+
+        let caller = self.blockchain().get_caller();
+        let seller = self.auctions(auction_id).get().seller;
+
+        require!(
+            caller == seller
+                || (seller == self.blockchain().get_owner_address()
+                    && self.has_role(Role::ListingManager, caller)),
+            ERR_NOT_AUCTION_SELLER
+        );
+    }
+
+    /** The role holders list for the marketplace itself, whose seller is the owner */
+    fn get_seller_of_caller(&self) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+
+        if self.holds_any_role(&caller) {
+            return self.blockchain().get_owner_address();
+        }
+
+        return caller;
+    }
+
+    fn get_english_auction(&self, auction_id: u64) -> EnglishAuction<Self::Api> {
+        require!(
+            !self.auctions(auction_id).is_empty(),
+            ERR_INVALID_AUCTION_ID
+        );
+
+        require!(
+            self.auction_kind(auction_id).get() == AuctionKind::English,
+            ERR_WRONG_AUCTION_KIND
+        );
+
+        return self.english_auctions(auction_id).get();
+    }
+
+    /** Returns the presale of the auction, if it is not over at `timestamp` */
+    fn get_running_presale(&self, auction_id: u64, timestamp: u64) -> Option<Presale<Self::Api>> {
+        if self.presales(auction_id).is_empty() {
+            return None;
+        }
+
+        let presale = self.presales(auction_id).get();
+
+        if timestamp >= presale.public_start_timestamp {
+            return None;
+        }
+
+        return Some(presale);
+    }
+
+    /** Items purchased during the presale count in the limit of the public sale */
+    fn get_max_per_wallet(
+        &self,
+        auction: &Auction<Self::Api>,
+        opt_presale: &Option<Presale<Self::Api>>,
+    ) -> BigUint {
+        match opt_presale {
+            Some(presale) if presale.max_per_wallet > 0 => presale.max_per_wallet.clone(),
+            _ => auction.max_per_wallet.clone(),
+        }
+    }
+
+    fn verify_allowlist_proof(
+        &self,
+        auction_id: u64,
+        address: &ManagedAddress,
+        proof: &AllowlistProof<Self::Api>,
+    ) -> bool {
+        if self.allowlist_merkle_root(auction_id).is_empty() {
+            return false;
+        }
+
+        let mut leaf_data = address.as_managed_buffer().clone();
+        leaf_data.append_bytes(&proof.allowance.to_be_bytes());
+
+        let mut node = self.crypto().keccak256(&leaf_data);
+
+        // the smallest hash of a pair goes first, so the proof doesn't need the sides
+        for sibling in proof.proof.iter() {
+            let mut pair_data = ManagedBuffer::new();
+
+            if node.to_byte_array() <= sibling.to_byte_array() {
+                pair_data.append(node.as_managed_buffer());
+                pair_data.append(sibling.as_managed_buffer());
+            } else {
+                pair_data.append(sibling.as_managed_buffer());
+                pair_data.append(node.as_managed_buffer());
+            }
+
+            node = self.crypto().keccak256(&pair_data);
+        }
+
+        return node == self.allowlist_merkle_root(auction_id).get();
+    }
+
+    fn require_no_bids(&self, auction_id: u64) {
+        if self.auction_kind(auction_id).get() != AuctionKind::English {
+            return;
+        }
+
+        require!(
+            self.english_auctions(auction_id).get().highest_bid == 0,
+            ERR_AUCTION_HAS_BIDS
+        );
+    }
+
+    /** Why the items of the auction can't be bought because of its status, if they can't */
+    fn get_auction_status_error(&self, auction: &Auction<Self::Api>) -> Option<&'static str> {
+        if self.paused().get() {
+            return Some(ERR_MARKETPLACE_PAUSED);
+        }
+
+        match auction.status {
+            AuctionStatus::Active => None,
+            AuctionStatus::Paused => Some(ERR_AUCTION_PAUSED),
+            AuctionStatus::Hidden => Some(ERR_SALE_IS_CLOSED),
+            AuctionStatus::Closed => Some(ERR_AUCTION_ARCHIVED),
+        }
+    }
+
+    /** Stores the auction and moves it between the active and inactive indexes */
+    fn save_auction(&self, auction_id: u64, auction: Auction<Self::Api>) {
+        if !self.auctions(auction_id).is_empty() {
+            match self.get_inactive_status(&self.auctions(auction_id).get()) {
+                Some(status) => self.inactive_auctions(status).remove(&auction_id),
+                None => self.active_auctions().remove(&auction_id),
+            };
+        }
+
+        match self.get_inactive_status(&auction) {
+            Some(status) => self.inactive_auctions(status).insert(auction_id),
+            None => self.active_auctions().insert(auction_id),
+        };
+
+        self.auctions(auction_id).set(auction);
+    }
+
+    /** The sale status of the auction when it doesn't depend on the block timestamp */
+    fn get_inactive_status(&self, auction: &Auction<Self::Api>) -> Option<SaleStatus> {
+        match auction.status {
+            AuctionStatus::Active if auction.current_quantity == 0 => Some(SaleStatus::SoldOut),
+            AuctionStatus::Active | AuctionStatus::Paused => None,
+            AuctionStatus::Hidden => Some(SaleStatus::Hidden),
+            AuctionStatus::Closed => Some(SaleStatus::Closed),
+        }
+    }
+
+    fn get_auction(&self, auction_id: u64) -> Auction<Self::Api> {
+        require!(
+            !self.auctions(auction_id).is_empty(),
+            ERR_INVALID_AUCTION_ID
+        );
+
+        return self.auctions(auction_id).get();
+    }
+
+    #[view(isAuctionPaused)]
+    fn is_auction_paused(&self, auction_id: u64) -> bool {
+        return self.get_auction(auction_id).status == AuctionStatus::Paused;
+    }
+
+    #[view(getAuctionStats)]
+    fn get_auction_stats(&self, auction_id: u64) -> AuctionStats<Self::Api> {
+        let auction = self.get_auction(auction_id);
+
+        return AuctionStats {
+            status: self.get_sale_status(&auction),
+            auction,
+            id: auction_id,
+            kind: self.auction_kind(auction_id).get(),
+        };
+    }
+
+    fn get_sale_status(&self, auction: &Auction<Self::Api>) -> SaleStatus {
+        let now = self.blockchain().get_block_timestamp();
+
+        match auction.status {
+            AuctionStatus::Active => {}
+            AuctionStatus::Paused => return SaleStatus::Paused,
+            AuctionStatus::Hidden => return SaleStatus::Hidden,
+            AuctionStatus::Closed => return SaleStatus::Closed,
+        }
+
+        if auction.current_quantity == 0 {
+            return SaleStatus::SoldOut;
+        }
+
+        if now < auction.start_timestamp {
+            return SaleStatus::Upcoming;
+        }
+
+        if auction.end_timestamp != 0 && now >= auction.end_timestamp {
+            return SaleStatus::Ended;
+        }
+
+        return SaleStatus::Live;
+    }
+
+    /**
+     * Returns how many items the buyer has bought from the auction,
+     * and how many more they can buy.
+     */
+    #[view(getWalletPurchases)]
+    fn get_wallet_purchases(
+        &self,
+        auction_id: u64,
+        buyer: ManagedAddress,
+    ) -> MultiValue2<BigUint, BigUint> {
+        let auction = self.get_auction(auction_id);
+        let purchased_quantity = self.purchased_quantity(auction_id, &buyer).get();
+        let opt_presale =
+            self.get_running_presale(auction_id, self.blockchain().get_block_timestamp());
+        let max_per_wallet = self.get_max_per_wallet(&auction, &opt_presale);
+
+        let mut buyable_quantity = auction.current_quantity;
+        if max_per_wallet > 0 {
+            let allowance = if purchased_quantity < max_per_wallet {
+                &max_per_wallet - &purchased_quantity
+            } else {
+                BigUint::zero()
+            };
+
+            if allowance < buyable_quantity {
+                buyable_quantity = allowance;
+            }
+        }
+
+        return (purchased_quantity, buyable_quantity).into();
+    }
+
+    /** Whether the address is allowed to buy from the auction right now */
+    #[view(isEligible)]
+    fn is_eligible(
+        &self,
+        auction_id: u64,
+        address: ManagedAddress,
+        opt_proof: OptionalValue<AllowlistProof<Self::Api>>,
+    ) -> bool {
+        let auction = self.get_auction(auction_id);
+        let now = self.blockchain().get_block_timestamp();
+
+        if self.get_auction_status_error(&auction).is_some()
+            || self.auction_kind(auction_id).get() == AuctionKind::English
+            || now < auction.start_timestamp
+            || (auction.end_timestamp != 0 && now >= auction.end_timestamp)
+        {
+            return false;
+        }
+
+        if self.get_running_presale(auction_id, now).is_none() {
+            return true;
+        }
+
+        return match opt_proof.into_option() {
+            Some(proof) => self.verify_allowlist_proof(auction_id, &address, &proof),
+            None => self.allowlist(auction_id).contains(&address),
+        };
+    }
+
+    #[view(getAllowlistMerkleRoot)]
+    fn get_allowlist_merkle_root(
+        &self,
+        auction_id: u64,
+    ) -> OptionalValue<ManagedByteArray<Self::Api, 32>> {
+        if self.allowlist_merkle_root(auction_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        return OptionalValue::Some(self.allowlist_merkle_root(auction_id).get());
+    }
+
+    /** Fee splits applied to the sales of the auction */
+    #[view(getFeeSplits)]
+    fn get_fee_splits(&self, auction_id: u64) -> ManagedVec<FeeShare<Self::Api>> {
+        require!(
+            !self.auctions(auction_id).is_empty(),
+            ERR_INVALID_AUCTION_ID
+        );
+
+        if self.auction_fee_splits(auction_id).is_empty() {
+            if !self.holds_any_role(&self.auctions(auction_id).get().seller) {
+                return ManagedVec::new();
+            }
+
+            return self.default_fee_splits().get();
+        }
+
+        return self.auction_fee_splits(auction_id).get();
+    }
+
+    /** Fees and royalties accrued by the payee and not claimed yet, per token */
+    #[view(getAccruedFees)]
+    fn get_accrued_fees(
+        &self,
+        payee: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue3<EgldOrEsdtTokenIdentifier, u64, BigUint>> {
+        let mut accrued_fees = MultiValueEncoded::new();
+
+        for (token_id, token_nonce) in self.accrued_fee_tokens(&payee).iter() {
+            let amount = self.accrued_fees(&payee, &token_id, token_nonce).get();
+
+            accrued_fees.push((token_id, token_nonce, amount).into());
+        }
+
+        return accrued_fees;
+    }
+
+    /** Proceeds credited to the seller over all their sales, withdrawn or not, per token */
+    #[view(getSellerEarnings)]
+    fn get_seller_earnings(
+        &self,
+        seller: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue3<EgldOrEsdtTokenIdentifier, u64, BigUint>> {
+        let mut earnings = MultiValueEncoded::new();
+
+        for (token_id, token_nonce) in self.seller_earning_tokens(&seller).iter() {
+            let amount = self.seller_earnings(&seller, &token_id, token_nonce).get();
+
+            earnings.push((token_id, token_nonce, amount).into());
+        }
+
+        return earnings;
+    }
+
+    /** Payment tokens accepted by the auction with their unit price, starting with the input token */
+    #[view(getPaymentTokens)]
+    fn get_payment_tokens(
+        &self,
+        auction_id: u64,
+    ) -> MultiValueEncoded<MultiValue3<EgldOrEsdtTokenIdentifier, u64, BigUint>> {
+        let auction = self.get_auction(auction_id);
+        let mut payment_tokens = MultiValueEncoded::new();
+
+        payment_tokens.push(
+            (
+                auction.input_token_id,
+                auction.input_token_nonce,
+                auction.price,
+            )
+                .into(),
+        );
+
+        for ((token_id, token_nonce), price) in self.extra_payment_tokens(auction_id).iter() {
+            payment_tokens.push((token_id, token_nonce, price).into());
+        }
+
+        return payment_tokens;
+    }
+
+    /** Proceeds not withdrawn yet in every payment token, starting with the input token */
+    #[view(getPendingProceedsPerToken)]
+    fn get_pending_proceeds_per_token(
+        &self,
+        auction_id: u64,
+    ) -> MultiValueEncoded<MultiValue3<EgldOrEsdtTokenIdentifier, u64, BigUint>> {
+        let auction = self.get_auction(auction_id);
+        let mut pending_proceeds = MultiValueEncoded::new();
+
+        pending_proceeds.push(
+            (
+                auction.input_token_id,
+                auction.input_token_nonce,
+                self.get_pending_proceeds(auction_id),
+            )
+                .into(),
+        );
+
+        for ((token_id, token_nonce), amount) in self.extra_pending_proceeds(auction_id).iter() {
+            pending_proceeds.push((token_id, token_nonce, amount).into());
+        }
+
+        return pending_proceeds;
+    }
+
+    /** Number of items left in the pool of a mystery box */
+    #[view(getMysteryBoxPoolSize)]
+    fn get_mystery_box_pool_size(&self, auction_id: u64) -> u64 {
+        require!(
+            !self.auctions(auction_id).is_empty(),
+            ERR_INVALID_AUCTION_ID
+        );
+
+        require!(
+            self.auction_kind(auction_id).get() == AuctionKind::MysteryBox,
+            ERR_WRONG_AUCTION_KIND
+        );
+
+        let mut size = 0;
+
+        for (_, remaining) in self.mystery_box_pool(auction_id).iter() {
+            size += remaining;
+        }
+
+        return size;
+    }
+
+    /** Tokens delivered for each item of a bundle auction */
+    #[view(getBundle)]
+    fn get_bundle(&self, auction_id: u64) -> ManagedVec<EsdtTokenPayment<Self::Api>> {
+        require!(
+            !self.auctions(auction_id).is_empty(),
+            ERR_INVALID_AUCTION_ID
+        );
+
+        require!(
+            self.auction_kind(auction_id).get() == AuctionKind::Bundle,
+            ERR_WRONG_AUCTION_KIND
+        );
+
+        return self.bundles(auction_id).get();
+    }
+
+    #[view(getPresale)]
+    fn get_presale(&self, auction_id: u64) -> OptionalValue<Presale<Self::Api>> {
+        require!(
+            !self.auctions(auction_id).is_empty(),
+            ERR_INVALID_AUCTION_ID
+        );
+
+        if self.presales(auction_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        return OptionalValue::Some(self.presales(auction_id).get());
+    }
+
+    /** Unit price charged by `buy` at the current block timestamp */
+    #[view(getCurrentPrice)]
+    fn get_current_price(&self, auction_id: u64) -> BigUint {
+        let auction = self.get_auction(auction_id);
+
+        match self.auction_kind(auction_id).get() {
+            AuctionKind::FixedPrice | AuctionKind::Bundle | AuctionKind::MysteryBox => self
+                .get_scheduled_price(
+                    auction_id,
+                    &auction,
+                    self.blockchain().get_block_timestamp(),
+                ),
+            AuctionKind::Dutch => self.get_dutch_price(
+                &auction,
+                &self.dutch_auctions(auction_id).get(),
+                self.blockchain().get_block_timestamp(),
+            ),
+            AuctionKind::BondingCurve => self.get_curve_cost(
+                &auction,
+                &self.bonding_curves(auction_id).get(),
+                &BigUint::from(1u64),
+            ),
+            AuctionKind::English => sc_panic!(ERR_WRONG_AUCTION_KIND),
+        }
+    }
+
+    /**
+     * Total cost of the next `quantity` items in the input token, as charged by `buy`
+     * to the allowlist during a presale and to everyone afterwards.
+     */
+    #[view]
+    fn quote(&self, auction_id: u64, quantity: BigUint) -> BigUint {
+        let auction = self.get_auction(auction_id);
+
+        return self.get_purchase_cost(
+            auction_id,
+            &auction.input_token_id,
+            auction.input_token_nonce,
+            &quantity,
+        );
+    }
+
+    #[view(getBondingCurve)]
+    fn get_bonding_curve(&self, auction_id: u64) -> BondingCurve<Self::Api> {
+        require!(
+            !self.auctions(auction_id).is_empty(),
+            ERR_INVALID_AUCTION_ID
+        );
+
+        require!(
+            self.auction_kind(auction_id).get() == AuctionKind::BondingCurve,
+            ERR_WRONG_AUCTION_KIND
+        );
+
+        return self.bonding_curves(auction_id).get();
+    }
+
+    /** Price of the last tier started at `timestamp`, or the one of the auction */
+    fn get_scheduled_price(
+        &self,
+        auction_id: u64,
+        auction: &Auction<Self::Api>,
+        timestamp: u64,
+    ) -> BigUint {
+        let mut price = auction.price.clone();
+
+        for tier in self.price_schedules(auction_id).get().iter() {
+            if tier.from_timestamp > timestamp {
+                break;
+            }
+
+            price = tier.price;
+        }
+
+        return price;
+    }
+
+    #[view(getPriceSchedule)]
+    fn get_price_schedule(&self, auction_id: u64) -> ManagedVec<PriceTier<Self::Api>> {
+        require!(
+            !self.auctions(auction_id).is_empty(),
+            ERR_INVALID_AUCTION_ID
+        );
+
+        return self.price_schedules(auction_id).get();
+    }
+
+    fn get_dutch_price(
+        &self,
+        auction: &Auction<Self::Api>,
+        dutch_auction: &DutchAuction<Self::Api>,
+        timestamp: u64,
+    ) -> BigUint {
+        if timestamp <= auction.start_timestamp {
+            return auction.price.clone();
+        }
+
+        if timestamp >= dutch_auction.floor_timestamp {
+            return dutch_auction.floor_price.clone();
+        }
+
+        let mut elapsed = timestamp - auction.start_timestamp;
+        if dutch_auction.price_step_duration > 0 {
+            elapsed -= elapsed % dutch_auction.price_step_duration;
+        }
+
+        let duration = dutch_auction.floor_timestamp - auction.start_timestamp;
+        let price_drop = (&auction.price - &dutch_auction.floor_price) * BigUint::from(elapsed)
+            / BigUint::from(duration);
+
+        return &auction.price - &price_drop;
+    }
+
+    #[view(getDutchAuction)]
+    fn get_dutch_auction(&self, auction_id: u64) -> DutchAuction<Self::Api> {
+        require!(
+            !self.auctions(auction_id).is_empty(),
+            ERR_INVALID_AUCTION_ID
+        );
+
+        require!(
+            self.auction_kind(auction_id).get() == AuctionKind::Dutch,
+            ERR_WRONG_AUCTION_KIND
+        );
+
+        return self.dutch_auctions(auction_id).get();
+    }
+
+    #[view(getEnglishAuction)]
+    fn get_english_auction_view(&self, auction_id: u64) -> EnglishAuction<Self::Api> {
+        return self.get_english_auction(auction_id);
+    }
+
+    /** Smallest amount accepted by `bid` right now */
+    #[view(getMinimumBid)]
+    fn get_minimum_bid(&self, auction_id: u64) -> BigUint {
+        let english_auction = self.get_english_auction(auction_id);
+
+        if english_auction.highest_bid == 0 {
+            return english_auction.reserve_price;
+        }
+
+        return english_auction.highest_bid + english_auction.min_bid_increment;
+    }
+
+    /** Proceeds not withdrawn yet in the input token of the auction */
+    #[view(getPendingProceeds)]
+    fn get_pending_proceeds(&self, auction_id: u64) -> BigUint {
+        require!(
+            !self.auctions(auction_id).is_empty(),
+            ERR_INVALID_AUCTION_ID
+        );
+
+        return self.collected_proceeds(auction_id).get()
+            - self.withdrawn_proceeds(auction_id).get();
+    }
+
+    /** Hidden and archived auctions are left out, unless `opt_include_hidden` is true */
+    #[view(getAllAuctionStats)]
+    fn get_all_auctions_stats(
+        &self,
+        opt_include_hidden: OptionalValue<bool>,
+    ) -> ManagedVec<Self::Api, AuctionStats<Self::Api>> {
+        let include_hidden = opt_include_hidden.into_option().unwrap_or_default();
+        let mut all_auctions = ManagedVec::new();
+
+        for auction_id in STARTING_AUCTION_ID..self.next_auction_id().get() {
+            if !self.is_listed(auction_id, include_hidden) {
+                continue;
+            }
+
+            let auction = self.get_auction_stats(auction_id);
+
+            all_auctions.push(auction);
+        }
+
+        return all_auctions;
+    }
+
+    /**
+     * The auctions among the `limit` ids from `from_id` included, in the order of their ids,
+     * so the next page starts at `from_id + limit`.
+     * Hidden and archived auctions are left out, unless `opt_include_hidden` is true.
+     */
+    #[view(getAuctionsPage)]
+    fn get_auctions_page(
+        &self,
+        from_id: u64,
+        limit: usize,
+        opt_include_hidden: OptionalValue<bool>,
+    ) -> ManagedVec<Self::Api, AuctionStats<Self::Api>> {
+        let include_hidden = opt_include_hidden.into_option().unwrap_or_default();
+        let first_id = core::cmp::max(from_id, STARTING_AUCTION_ID);
+        let last_id = core::cmp::min(
+            first_id.saturating_add(core::cmp::min(limit, MAX_PAGE_SIZE) as u64),
+            self.next_auction_id().get(),
+        );
+        let auction_ids =
+            (first_id..last_id).filter(|auction_id| self.is_listed(*auction_id, include_hidden));
+
+        return self.get_indexed_auctions_page(auction_ids, 0, limit);
+    }
+
+    /**
+     * The filtered views below return at most `limit` auctions, skipping the first `offset` ones.
+     * Their order is the one of the index, not the one of the ids.
+     */
+    #[view(getActiveAuctions)]
+    fn get_active_auctions(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> ManagedVec<Self::Api, AuctionStats<Self::Api>> {
+        // the paused auctions are indexed with the active ones
+        let active_auctions = self.active_auctions();
+        let auction_ids = active_auctions
+            .iter()
+            .filter(|auction_id| self.auctions(*auction_id).get().status == AuctionStatus::Active);
+
+        return self.get_indexed_auctions_page(auction_ids, offset, limit);
+    }
+
+    /** Every auction of the seller, hidden and archived ones included */
+    #[view(getAuctionsBySeller)]
+    fn get_auctions_by_seller(
+        &self,
+        seller: ManagedAddress,
+        offset: usize,
+        limit: usize,
+    ) -> ManagedVec<Self::Api, AuctionStats<Self::Api>> {
+        let index = self.auctions_by_seller(&seller);
+        let auction_ids = index.iter();
+
+        return self.get_indexed_auctions_page(auction_ids, offset, limit);
+    }
+
+    /** Hidden and archived auctions are left out, unless `opt_include_hidden` is true */
+    #[view(getAuctionsByOutputToken)]
+    fn get_auctions_by_output_token(
+        &self,
+        token_id: TokenIdentifier,
+        offset: usize,
+        limit: usize,
+        opt_include_hidden: OptionalValue<bool>,
+    ) -> ManagedVec<Self::Api, AuctionStats<Self::Api>> {
+        let include_hidden = opt_include_hidden.into_option().unwrap_or_default();
+        let index = self.auctions_by_output_token(&token_id);
+        let auction_ids = index
+            .iter()
+            .filter(|auction_id| self.is_listed(*auction_id, include_hidden));
+
+        return self.get_indexed_auctions_page(auction_ids, offset, limit);
+    }
+
+    /** Hidden and archived auctions are left out, unless `opt_include_hidden` is true */
+    #[view(getAuctionsByPaymentToken)]
+    fn get_auctions_by_payment_token(
+        &self,
+        token_id: EgldOrEsdtTokenIdentifier,
+        offset: usize,
+        limit: usize,
+        opt_include_hidden: OptionalValue<bool>,
+    ) -> ManagedVec<Self::Api, AuctionStats<Self::Api>> {
+        let include_hidden = opt_include_hidden.into_option().unwrap_or_default();
+        let index = self.auctions_by_payment_token(&token_id);
+        let auction_ids = index
+            .iter()
+            .filter(|auction_id| self.is_listed(*auction_id, include_hidden));
+
+        return self.get_indexed_auctions_page(auction_ids, offset, limit);
+    }
+
+    /**
+     * Upcoming, live, ended and paused auctions are searched among the active ones,
+     * sold out, hidden and archived auctions have an index of their own.
+     */
+    #[view(getAuctionsByStatus)]
+    fn get_auctions_by_status(
+        &self,
+        status: SaleStatus,
+        offset: usize,
+        limit: usize,
+    ) -> ManagedVec<Self::Api, AuctionStats<Self::Api>> {
+        if let SaleStatus::SoldOut | SaleStatus::Hidden | SaleStatus::Closed = status {
+            let index = self.inactive_auctions(status);
+
+            return self.get_indexed_auctions_page(index.iter(), offset, limit);
+        }
+
+        let mut page = ManagedVec::new();
+
+        for stats in self
+            .active_auctions()
+            .iter()
+            .map(|auction_id| self.get_auction_stats(auction_id))
+            .filter(|stats| stats.status == status)
+            .skip(offset)
+            .take(core::cmp::min(limit, MAX_PAGE_SIZE))
+        {
+            page.push(stats);
+        }
+
+        return page;
+    }
+
+    fn get_indexed_auctions_page<I: Iterator<Item = u64>>(
+        &self,
+        auction_ids: I,
+        offset: usize,
+        limit: usize,
+    ) -> ManagedVec<Self::Api, AuctionStats<Self::Api>> {
+        let mut page = ManagedVec::new();
+
+        for auction_id in auction_ids
+            .skip(offset)
+            .take(core::cmp::min(limit, MAX_PAGE_SIZE))
+        {
+            page.push(self.get_auction_stats(auction_id));
+        }
+
+        return page;
+    }
+
+    fn is_listed(&self, auction_id: u64, include_hidden: bool) -> bool {
+        if include_hidden {
+            return true;
+        }
+
+        let status = self.auctions(auction_id).get().status;
+
+        return status != AuctionStatus::Hidden && status != AuctionStatus::Closed;
+    }
+
+    /**
+     * The contract holds the items of every seller and every kind of auction,
+     * so its balance of a token is not the stock of a single auction.
+     */
+    fn get_remaining_amount(&self, auction: &Auction<Self::Api>) -> BigUint<Self::Api> {
+        return auction.current_quantity.clone();
     }
 }
@@ -0,0 +1,225 @@
+multiversx_sc::imports!();
+
+use crate::{
+    auction::{FeeShare, PriceTier},
+    roles::Role,
+};
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    #[event("createAuction")]
+    fn create_auction_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] output_token_id: &TokenIdentifier,
+        #[indexed] output_token_nonce: u64,
+        #[indexed] input_token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] input_token_nonce: u64,
+        #[indexed] price: &BigUint,
+        #[indexed] quantity: &BigUint,
+    );
+
+    #[event("addTokenToAuction")]
+    fn add_token_to_auction_event(&self, #[indexed] auction_id: u64, #[indexed] quantity: &BigUint);
+
+    #[event("retireTokenFromAuction")]
+    fn retire_token_from_auction_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] quantity: &BigUint,
+    );
+
+    #[event("withdrawBalance")]
+    fn withdraw_balance_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] receiver: &ManagedAddress,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] token_nonce: u64,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("hideAuction")]
+    fn hide_auction_event(&self, #[indexed] auction_id: u64);
+
+    #[event("grantRole")]
+    fn grant_role_event(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    #[event("revokeRole")]
+    fn revoke_role_event(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    #[event("pause")]
+    fn pause_event(&self);
+
+    #[event("unpause")]
+    fn unpause_event(&self);
+
+    #[event("pauseAuction")]
+    fn pause_auction_event(&self, #[indexed] auction_id: u64);
+
+    #[event("unpauseAuction")]
+    fn unpause_auction_event(&self, #[indexed] auction_id: u64);
+
+    #[event("archiveAuction")]
+    fn archive_auction_event(&self, #[indexed] auction_id: u64);
+
+    #[event("buy")]
+    fn buy_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] buyer: &ManagedAddress,
+        #[indexed] output_token_id: &TokenIdentifier,
+        #[indexed] output_token_nonce: u64,
+        #[indexed] quantity: &BigUint,
+        #[indexed] price_paid: &BigUint,
+        #[indexed] payment_token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] payment_token_nonce: u64,
+    );
+
+    #[event("bid")]
+    fn bid_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] bidder: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("claim")]
+    fn claim_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] receiver: &ManagedAddress,
+        #[indexed] quantity: &BigUint,
+        #[indexed] winning_bid: &BigUint,
+    );
+
+    #[event("setPresale")]
+    fn set_presale_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] public_start_timestamp: u64,
+        #[indexed] price: &BigUint,
+        #[indexed] max_per_wallet: &BigUint,
+    );
+
+    #[event("clearPresale")]
+    fn clear_presale_event(&self, #[indexed] auction_id: u64);
+
+    #[event("setPriceSchedule")]
+    fn set_price_schedule_event(
+        &self,
+        #[indexed] auction_id: u64,
+        tiers: &ManagedVec<PriceTier<Self::Api>>,
+    );
+
+    #[event("addToAllowlist")]
+    fn add_to_allowlist_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] address: &ManagedAddress,
+    );
+
+    #[event("removeFromAllowlist")]
+    fn remove_from_allowlist_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] address: &ManagedAddress,
+    );
+
+    #[event("setAllowlistMerkleRoot")]
+    fn set_allowlist_merkle_root_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] merkle_root: &ManagedByteArray<Self::Api, 32>,
+    );
+
+    #[event("clearAllowlistMerkleRoot")]
+    fn clear_allowlist_merkle_root_event(&self, #[indexed] auction_id: u64);
+
+    #[event("setPlatformFee")]
+    fn set_platform_fee_event(&self, #[indexed] payee: &ManagedAddress, bps: u64);
+
+    #[event("setDefaultFeeSplits")]
+    fn set_default_fee_splits_event(&self, fee_splits: &ManagedVec<FeeShare<Self::Api>>);
+
+    #[event("setAuctionFeeSplits")]
+    fn set_auction_fee_splits_event(
+        &self,
+        #[indexed] auction_id: u64,
+        fee_splits: &ManagedVec<FeeShare<Self::Api>>,
+    );
+
+    #[event("claimFees")]
+    fn claim_fees_event(
+        &self,
+        #[indexed] payee: &ManagedAddress,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] token_nonce: u64,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("setHonorRoyalties")]
+    fn set_honor_royalties_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] honor_royalties: bool,
+    );
+
+    #[event("royalties")]
+    fn royalties_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] creator: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("createBundle")]
+    fn create_bundle_event(
+        &self,
+        #[indexed] auction_id: u64,
+        components: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+    );
+
+    #[event("setPaymentTokenPrice")]
+    fn set_payment_token_price_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] token_nonce: u64,
+        #[indexed] price: &BigUint,
+    );
+
+    #[event("updateAuctionPrice")]
+    fn update_auction_price_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] old_price: &BigUint,
+        #[indexed] new_price: &BigUint,
+    );
+
+    #[event("updateAuctionStartTimestamp")]
+    fn update_auction_start_timestamp_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] old_start_timestamp: u64,
+        #[indexed] new_start_timestamp: u64,
+    );
+
+    #[event("updateAuctionPaymentToken")]
+    fn update_auction_payment_token_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] old_token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] old_token_nonce: u64,
+        #[indexed] new_token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] new_token_nonce: u64,
+    );
+
+    #[event("removePaymentToken")]
+    fn remove_payment_token_event(
+        &self,
+        #[indexed] auction_id: u64,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] token_nonce: u64,
+    );
+}
@@ -0,0 +1,112 @@
+//! Off-chain helpers to build the Merkle tree of an allowlist and the proofs accepted by `buy`.
+//!
+//! The contract checks the proofs with `keccak256`:
+//! - a leaf is the hash of the 32 bytes of the address followed by the allowance as 8 big-endian bytes
+//! - a parent is the hash of its two children, the smallest one first
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use multiversx_sc::types::Address;
+use sha3::{Digest, Keccak256};
+
+pub type Hash = [u8; 32];
+
+pub fn keccak256(data: &[u8]) -> Hash {
+    return Keccak256::digest(data).into();
+}
+
+/** `allowance` is the maximum number of items the address can buy during the presale, 0 if it has none of its own */
+pub fn allowlist_leaf(address: &Address, allowance: u64) -> Hash {
+    let mut data = Vec::with_capacity(40);
+    data.extend_from_slice(address.as_array());
+    data.extend_from_slice(&allowance.to_be_bytes());
+
+    return keccak256(&data);
+}
+
+pub fn hash_pair(left: &Hash, right: &Hash) -> Hash {
+    let (first, second) = if left <= right {
+        (left, right)
+    } else {
+        (right, left)
+    };
+
+    let mut data = Vec::with_capacity(64);
+    data.extend_from_slice(first);
+    data.extend_from_slice(second);
+
+    return keccak256(&data);
+}
+
+pub fn verify(root: &Hash, leaf: &Hash, proof: &[Hash]) -> bool {
+    let computed_root = proof
+        .iter()
+        .fold(*leaf, |node, sibling| hash_pair(&node, sibling));
+
+    return &computed_root == root;
+}
+
+pub struct MerkleTree {
+    /** from the leaves to the root, the last node of an odd level is moved up as is */
+    levels: Vec<Vec<Hash>>,
+}
+
+impl MerkleTree {
+    pub fn new(leaves: Vec<Hash>) -> Self {
+        assert!(!leaves.is_empty(), "A Merkle tree needs at least one leaf");
+
+        let mut levels = Vec::new();
+        levels.push(leaves);
+
+        while levels[levels.len() - 1].len() > 1 {
+            let level = &levels[levels.len() - 1];
+            let parents = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_pair(left, right),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+
+            levels.push(parents);
+        }
+
+        return MerkleTree { levels };
+    }
+
+    /** Builds the tree of an allowlist made of `(address, allowance)` pairs */
+    pub fn from_allowlist(allowlist: &[(Address, u64)]) -> Self {
+        let leaves = allowlist
+            .iter()
+            .map(|(address, allowance)| allowlist_leaf(address, *allowance))
+            .collect();
+
+        return MerkleTree::new(leaves);
+    }
+
+    pub fn root(&self) -> Hash {
+        return self.levels[self.levels.len() - 1][0];
+    }
+
+    /** Returns the siblings of the leaf at `index`, from the bottom to the top of the tree */
+    pub fn proof(&self, index: usize) -> Vec<Hash> {
+        assert!(index < self.levels[0].len(), "Leaf index out of bounds");
+
+        let mut proof = Vec::new();
+        let mut index = index;
+
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling_index = index ^ 1;
+
+            if sibling_index < level.len() {
+                proof.push(level[sibling_index]);
+            }
+
+            index /= 2;
+        }
+
+        return proof;
+    }
+}
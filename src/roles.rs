@@ -0,0 +1,91 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{events, ERR_MISSING_ROLE, ERR_REVOKING_LAST_ADMIN};
+
+/** The admins can call every endpoint */
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Copy, Debug,
+)]
+pub enum Role {
+    Admin,
+    /** Edits and retires the auctions listed by the role holders */
+    ListingManager,
+    /** Sets the platform fee and the default fee splits */
+    Treasurer,
+    /** Pauses and unpauses the marketplace and the auctions */
+    Pauser,
+}
+
+#[multiversx_sc::module]
+pub trait RolesModule: events::EventsModule {
+    #[view(getRoleHolders)]
+    #[storage_mapper("role_holders")]
+    fn role_holders(&self, role: Role) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: Role, address: ManagedAddress) {
+        self.require_role(Role::Admin);
+
+        self.grant_role_event(role, &address);
+        self.role_holders(role).insert(address);
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: Role, address: ManagedAddress) {
+        self.require_role(Role::Admin);
+
+        require!(
+            role != Role::Admin
+                || self.role_holders(Role::Admin).len() > 1
+                || !self.role_holders(Role::Admin).contains(&address),
+            ERR_REVOKING_LAST_ADMIN
+        );
+
+        self.revoke_role_event(role, &address);
+        self.role_holders(role).swap_remove(&address);
+    }
+
+    #[view(hasRole)]
+    fn has_role(&self, role: Role, address: ManagedAddress) -> bool {
+        return self.role_holders(role).contains(&address)
+            || self.role_holders(Role::Admin).contains(&address);
+    }
+
+    #[view(getRoles)]
+    fn get_roles(&self, address: ManagedAddress) -> MultiValueEncoded<Role> {
+        let mut roles = MultiValueEncoded::new();
+
+        for role in [
+            Role::Admin,
+            Role::ListingManager,
+            Role::Treasurer,
+            Role::Pauser,
+        ] {
+            if self.role_holders(role).contains(&address) {
+                roles.push(role);
+            }
+        }
+
+        return roles;
+    }
+
+    /** The auctions of the role holders are the ones of the marketplace itself */
+    fn holds_any_role(&self, address: &ManagedAddress) -> bool {
+        return [
+            Role::Admin,
+            Role::ListingManager,
+            Role::Treasurer,
+            Role::Pauser,
+        ]
+        .iter()
+        .any(|role| self.role_holders(*role).contains(address));
+    }
+
+    fn require_role(&self, role: Role) {
+        require!(
+            self.has_role(role, self.blockchain().get_caller()),
+            ERR_MISSING_ROLE
+        );
+    }
+}
@@ -1,9 +1,12 @@
 use apc_sales::{
-    EmptyContract, ERR_INVALID_AUCTION_ID, ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH,
+    auction::{AuctionStatus, SaleStatus},
+    EmptyContract, ERR_ARCHIVE_AUCTION_HAS_ITEMS, ERR_ARCHIVE_AUCTION_HAS_PROCEEDS,
+    ERR_AUCTION_ARCHIVED, ERR_INVALID_AUCTION_ID, ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH,
     ERR_INVALID_PAYMENT_TOKEN_NONCE_MISMATCH, ERR_RETIRING_TOO_MUCH_TOKENS, STARTING_AUCTION_ID,
 };
+use multiversx_sc::codec::multi_types::OptionalValue;
 use multiversx_sc::types::BoxedBytes;
-use multiversx_sc_scenario::{managed_biguint, rust_biguint};
+use multiversx_sc_scenario::{managed_biguint, rust_biguint, DebugApi};
 
 use crate::helpers;
 
@@ -94,7 +97,11 @@ fn retire_tokens_fail_if_too_much_tokens() {
             &setup.contract_wrapper,
             &rust_biguint!(PRICE * BUY_QUANTITY),
             |sc| {
-                sc.buy(STARTING_AUCTION_ID);
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -273,7 +280,11 @@ fn withdraw_works_with_egld() {
             &setup.contract_wrapper,
             &rust_biguint!(PRICE),
             |sc| {
-                sc.buy(STARTING_AUCTION_ID);
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -342,7 +353,11 @@ fn withdraw_works_with_esdt() {
             INPUT_TOKEN_NONCE,
             &rust_biguint!(PRICE),
             |sc| {
-                sc.buy(STARTING_AUCTION_ID);
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -399,7 +414,11 @@ fn hide_auction_works() {
             &setup.contract_wrapper,
             &rust_biguint!(PRICE * BUY_QUANTITY),
             |sc| {
-                sc.buy(STARTING_AUCTION_ID);
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -414,16 +433,15 @@ fn hide_auction_works() {
         )
         .assert_ok();
 
-    // assert max_quantity == 0
+    // the sold items are still counted
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
             let auction_stats = sc.get_auction_stats(STARTING_AUCTION_ID);
 
-            assert_eq!(
-                auction_stats.auction.max_quantity, 0,
-                "The max quantity should be 0"
-            );
+            assert_eq!(auction_stats.auction.status, AuctionStatus::Hidden);
+            assert_eq!(auction_stats.auction.max_quantity, BUY_QUANTITY);
+            assert_eq!(auction_stats.auction.current_quantity, 0u64);
         })
         .assert_ok();
 
@@ -502,7 +520,11 @@ fn withdraw_esdt_do_not_exceed() {
             INPUT_TOKEN_NONCE,
             &rust_biguint!(PRICE),
             |sc| {
-                sc.buy(STARTING_AUCTION_ID);
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -565,7 +587,11 @@ fn withdraw_egld_do_not_exceed() {
             &setup.contract_wrapper,
             &rust_biguint!(PRICE),
             |sc| {
-                sc.buy(STARTING_AUCTION_ID);
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -602,3 +628,154 @@ fn withdraw_egld_do_not_exceed() {
         &rust_biguint!(EXCEED_EGLD),
     );
 }
+
+#[test]
+fn withdraw_twice_do_not_send_twice() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const INITIAL_QUANTITY: u64 = 100;
+    const PRICE: u64 = 10;
+    const RESERVE_EGLD: u64 = 1_000;
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, INITIAL_QUANTITY);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+
+    // simulate the funds of later sales, that must not be drained
+    setup.blockchain_wrapper.set_egld_balance(
+        &setup.contract_wrapper.address_ref(),
+        &rust_biguint!(PRICE + RESERVE_EGLD),
+    );
+
+    setup.withdraw_balance();
+    setup.withdraw_balance();
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&setup.owner_address, &rust_biguint!(PRICE));
+
+    setup.blockchain_wrapper.check_egld_balance(
+        &setup.contract_wrapper.address_ref(),
+        &rust_biguint!(RESERVE_EGLD),
+    );
+}
+
+#[test]
+fn withdraw_interleaved_with_buy_only_sends_delta() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const INITIAL_QUANTITY: u64 = 100;
+    const PRICE: u64 = 10;
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, INITIAL_QUANTITY);
+
+    // 1. first sale then withdraw
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+    setup.withdraw_balance();
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&setup.owner_address, &rust_biguint!(PRICE));
+
+    // 2. two more sales, withdraw twice
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * 2);
+    setup.withdraw_balance();
+    setup.withdraw_balance();
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&setup.owner_address, &rust_biguint!(PRICE * 3));
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&setup.contract_wrapper.address_ref(), &rust_biguint!(0));
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.collected_proceeds(STARTING_AUCTION_ID).get(), PRICE * 3);
+            assert_eq!(sc.withdrawn_proceeds(STARTING_AUCTION_ID).get(), PRICE * 3);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn withdraw_after_hide_auction_sends_proceeds() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const INITIAL_QUANTITY: u64 = 100;
+    const PRICE: u64 = 10;
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, INITIAL_QUANTITY);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.hide_auction(STARTING_AUCTION_ID),
+        )
+        .assert_ok();
+
+    setup.withdraw_balance();
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&setup.owner_address, &rust_biguint!(PRICE));
+}
+
+fn archive_auction<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    expected_error: Option<&str>,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(0),
+        |sc| sc.archive_auction(STARTING_AUCTION_ID),
+    );
+
+    match expected_error {
+        Some(message) => tx_result.assert_user_error(message),
+        None => tx_result.assert_ok(),
+    }
+}
+
+#[test]
+fn archive_auction_works() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 10;
+    const QUANTITY: u64 = 2;
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+
+    archive_auction(&mut setup, Some(ERR_ARCHIVE_AUCTION_HAS_ITEMS));
+
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * QUANTITY);
+
+    archive_auction(&mut setup, Some(ERR_ARCHIVE_AUCTION_HAS_PROCEEDS));
+
+    setup.withdraw_balance();
+
+    archive_auction(&mut setup, None);
+    archive_auction(&mut setup, Some(ERR_AUCTION_ARCHIVED));
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let auction_stats = sc.get_auction_stats(STARTING_AUCTION_ID);
+
+            assert_eq!(auction_stats.auction.status, AuctionStatus::Closed);
+            assert_eq!(auction_stats.status, SaleStatus::Closed);
+            assert!(sc.get_all_auctions_stats(OptionalValue::None).is_empty());
+            assert_eq!(
+                sc.get_all_auctions_stats(OptionalValue::Some(true)).len(),
+                1
+            );
+        })
+        .assert_ok();
+}
@@ -0,0 +1,277 @@
+use apc_sales::{
+    auction::BondingCurve, EmptyContract, ERR_BONDING_CURVE_TOO_LONG, ERR_CREATE_AUCTION_BAD_CURVE,
+    ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH, ERR_NOT_ENOUGHT_ITEMS,
+    MAX_EXPONENTIAL_CURVE_QUANTITY, STARTING_AUCTION_ID,
+};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{BoxedBytes, EgldOrEsdtTokenIdentifier, MultiValueEncoded};
+use multiversx_sc_scenario::{managed_biguint, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+const QUANTITY: u64 = 10;
+const LINEAR_START_PRICE: u64 = 100;
+const LINEAR_INCREMENT: u64 = 10;
+const EXPONENTIAL_START_PRICE: u64 = 10_000;
+const EXPONENTIAL_GROWTH_BPS: u64 = 1_000;
+
+fn linear_curve() -> BondingCurve<DebugApi> {
+    BondingCurve::Linear {
+        increment: managed_biguint!(LINEAR_INCREMENT),
+    }
+}
+
+fn exponential_curve() -> BondingCurve<DebugApi> {
+    BondingCurve::Exponential {
+        growth_bps: EXPONENTIAL_GROWTH_BPS,
+    }
+}
+
+fn create_bonding_curve_auction<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    start_price: u64,
+    curve: fn() -> BondingCurve<DebugApi>,
+    quantity: u64,
+    expected_error: Option<&str>,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    let tx_result = setup.create_default_auction_with(quantity, |sc| {
+        let _ = sc.create_bonding_curve_auction(
+            EgldOrEsdtTokenIdentifier::egld(),
+            0,
+            managed_biguint!(start_price),
+            curve(),
+            0,
+            OptionalValue::None,
+            OptionalValue::None,
+        );
+    });
+
+    match expected_error {
+        Some(message) => tx_result.assert_user_error(message),
+        None => tx_result.assert_ok(),
+    }
+}
+
+fn buy_quantity<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    quantity: u64,
+    payment: u64,
+    expected_error: Option<&str>,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(payment));
+
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.user_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(payment),
+        |sc| {
+            sc.buy(
+                STARTING_AUCTION_ID,
+                OptionalValue::Some(managed_biguint!(quantity)),
+                OptionalValue::None,
+            );
+        },
+    );
+
+    match expected_error {
+        Some(message) => tx_result.assert_user_error(message),
+        None => tx_result.assert_ok(),
+    }
+}
+
+fn check_quote<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    quantity: u64,
+    expected_cost: u64,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.quote(STARTING_AUCTION_ID, managed_biguint!(quantity)),
+                expected_cost
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn linear_curve_charges_the_integrated_price() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_bonding_curve_auction(&mut setup, LINEAR_START_PRICE, linear_curve, QUANTITY, None);
+
+    // 100 + 110 + 120
+    check_quote(&mut setup, 3, 330);
+    buy_quantity(
+        &mut setup,
+        3,
+        329,
+        Some(ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH),
+    );
+    buy_quantity(&mut setup, 3, 330, None);
+
+    // 130 + 140
+    check_quote(&mut setup, 2, 270);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_current_price(STARTING_AUCTION_ID), 130u64);
+            assert_eq!(sc.get_pending_proceeds(STARTING_AUCTION_ID), 330u64);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn exponential_curve_charges_the_integrated_price() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_bonding_curve_auction(
+        &mut setup,
+        EXPONENTIAL_START_PRICE,
+        exponential_curve,
+        QUANTITY,
+        None,
+    );
+
+    // 10_000 + 11_000 + 12_100
+    check_quote(&mut setup, 3, 33_100);
+    buy_quantity(&mut setup, 1, EXPONENTIAL_START_PRICE, None);
+
+    // 11_000 + 12_100
+    check_quote(&mut setup, 2, 23_100);
+    buy_quantity(&mut setup, 2, 23_100, None);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_current_price(STARTING_AUCTION_ID), 13_310u64);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_without_quantity_sells_what_the_payment_covers() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_bonding_curve_auction(&mut setup, LINEAR_START_PRICE, linear_curve, QUANTITY, None);
+
+    // 330 buys 3 items, the 4th one would cost 130 more
+    setup.buy_in_egld(STARTING_AUCTION_ID, 400);
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&setup.user_address, &rust_biguint!(70));
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.user_address,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(3),
+        Option::Some(&BoxedBytes::empty()),
+    );
+}
+
+#[test]
+fn quote_fails_if_not_enough_items() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_bonding_curve_auction(&mut setup, LINEAR_START_PRICE, linear_curve, QUANTITY, None);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let _ = sc.quote(STARTING_AUCTION_ID, managed_biguint!(QUANTITY + 1));
+        })
+        .assert_user_error(ERR_NOT_ENOUGHT_ITEMS);
+}
+
+#[test]
+fn create_bonding_curve_auction_fails_if_price_does_not_grow() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_bonding_curve_auction(
+        &mut setup,
+        LINEAR_START_PRICE,
+        || BondingCurve::Linear {
+            increment: managed_biguint!(0),
+        },
+        QUANTITY,
+        Some(ERR_CREATE_AUCTION_BAD_CURVE),
+    );
+}
+
+#[test]
+fn create_exponential_curve_fails_if_too_many_items() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_bonding_curve_auction(
+        &mut setup,
+        EXPONENTIAL_START_PRICE,
+        exponential_curve,
+        MAX_EXPONENTIAL_CURVE_QUANTITY + 1,
+        Some(ERR_BONDING_CURVE_TOO_LONG),
+    );
+}
+
+#[test]
+fn sold_out_exponential_curve_fails_with_not_enough_items() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_bonding_curve_auction(
+        &mut setup,
+        EXPONENTIAL_START_PRICE,
+        || BondingCurve::Exponential { growth_bps: 100 },
+        MAX_EXPONENTIAL_CURVE_QUANTITY,
+        None,
+    );
+
+    let mut cost = 0u64;
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            cost = sc
+                .quote(
+                    STARTING_AUCTION_ID,
+                    managed_biguint!(MAX_EXPONENTIAL_CURVE_QUANTITY),
+                )
+                .to_u64()
+                .unwrap();
+        })
+        .assert_ok();
+
+    buy_quantity(&mut setup, MAX_EXPONENTIAL_CURVE_QUANTITY, cost, None);
+
+    // the next item would be past the end of the curve
+    buy_quantity(&mut setup, 1, cost, Some(ERR_NOT_ENOUGHT_ITEMS));
+
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(cost));
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(cost),
+            |sc| {
+                let mut cart = MultiValueEncoded::new();
+                cart.push((STARTING_AUCTION_ID, managed_biguint!(1)).into());
+
+                sc.buy_cart(cart);
+            },
+        )
+        .assert_user_error(&format!(
+            "Auction {}: {}",
+            STARTING_AUCTION_ID, ERR_NOT_ENOUGHT_ITEMS
+        ));
+}
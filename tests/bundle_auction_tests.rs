@@ -0,0 +1,279 @@
+use apc_sales::{
+    auction::AuctionKind, EmptyContract, ERR_CREATE_AUCTION_BAD_BUNDLE_QUANTITY,
+    ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH, ERR_WRONG_AUCTION_KIND, STARTING_AUCTION_ID,
+};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{BoxedBytes, EgldOrEsdtTokenIdentifier};
+use multiversx_sc_scenario::{
+    managed_biguint, managed_token_id, rust_biguint, testing_framework::TxTokenTransfer, DebugApi,
+};
+
+use crate::helpers;
+
+const PRICE: u64 = 100;
+const PENGUIN_TOKEN: &[u8] = b"PENGUIN-aaaaaa";
+const PENGUIN_NONCE: u64 = 5;
+const ITEM_TOKEN: &[u8] = b"ITEM-bbbbbb";
+const ITEM_NONCE: u64 = 1;
+/** Each bundle holds one penguin and two items */
+const ITEMS_PER_BUNDLE: u64 = 2;
+
+fn bundle_deposit(bundles: u64) -> Vec<TxTokenTransfer> {
+    return vec![
+        TxTokenTransfer {
+            token_identifier: PENGUIN_TOKEN.to_vec(),
+            nonce: PENGUIN_NONCE,
+            value: rust_biguint!(bundles),
+        },
+        TxTokenTransfer {
+            token_identifier: ITEM_TOKEN.to_vec(),
+            nonce: ITEM_NONCE,
+            value: rust_biguint!(bundles * ITEMS_PER_BUNDLE),
+        },
+    ];
+}
+
+fn set_owner_bundle_balances<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    bundles: u64,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.owner_address,
+        PENGUIN_TOKEN,
+        PENGUIN_NONCE,
+        &rust_biguint!(bundles),
+        &BoxedBytes::empty(),
+    );
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.owner_address,
+        ITEM_TOKEN,
+        ITEM_NONCE,
+        &rust_biguint!(bundles * ITEMS_PER_BUNDLE),
+        &BoxedBytes::empty(),
+    );
+}
+
+fn create_bundle_auction<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    bundles: u64,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    set_owner_bundle_balances(setup, bundles);
+
+    setup
+        .blockchain_wrapper
+        .execute_esdt_multi_transfer(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &bundle_deposit(bundles),
+            |sc| {
+                let _ = sc.create_auction(
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    0,
+                    managed_biguint!(PRICE),
+                    0,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::Some(managed_biguint!(bundles)),
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn create_bundle_auction_splits_deposit() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_bundle_auction(&mut setup, 3);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let stats = sc.get_auction_stats(STARTING_AUCTION_ID);
+
+            assert_eq!(stats.kind, AuctionKind::Bundle);
+            assert_eq!(stats.auction.current_quantity, managed_biguint!(3));
+            assert_eq!(
+                stats.auction.output_token_id,
+                managed_token_id!(PENGUIN_TOKEN)
+            );
+
+            let bundle = sc.get_bundle(STARTING_AUCTION_ID);
+
+            assert_eq!(bundle.len(), 2);
+            assert_eq!(
+                bundle.get(0).token_identifier,
+                managed_token_id!(PENGUIN_TOKEN)
+            );
+            assert_eq!(bundle.get(0).amount, managed_biguint!(1));
+            assert_eq!(
+                bundle.get(1).token_identifier,
+                managed_token_id!(ITEM_TOKEN)
+            );
+            assert_eq!(bundle.get(1).amount, managed_biguint!(ITEMS_PER_BUNDLE));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn create_bundle_auction_fails_if_deposit_is_not_a_multiple() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    set_owner_bundle_balances(&mut setup, 2);
+
+    setup
+        .blockchain_wrapper
+        .execute_esdt_multi_transfer(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &bundle_deposit(2),
+            |sc| {
+                let _ = sc.create_auction(
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    0,
+                    managed_biguint!(PRICE),
+                    0,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::Some(managed_biguint!(4)),
+                );
+            },
+        )
+        .assert_user_error(ERR_CREATE_AUCTION_BAD_BUNDLE_QUANTITY);
+}
+
+#[test]
+fn buy_delivers_every_component() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_bundle_auction(&mut setup, 3);
+
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * 2);
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.user_address,
+        PENGUIN_TOKEN,
+        PENGUIN_NONCE,
+        &rust_biguint!(2),
+        Option::Some(&BoxedBytes::empty()),
+    );
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.user_address,
+        ITEM_TOKEN,
+        ITEM_NONCE,
+        &rust_biguint!(2 * ITEMS_PER_BUNDLE),
+        Option::Some(&BoxedBytes::empty()),
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_auction_stats(STARTING_AUCTION_ID)
+                    .auction
+                    .current_quantity,
+                managed_biguint!(1)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn add_token_to_bundle_auction() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_bundle_auction(&mut setup, 1);
+    set_owner_bundle_balances(&mut setup, 2);
+
+    setup
+        .blockchain_wrapper
+        .execute_esdt_multi_transfer(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &bundle_deposit(2),
+            |sc| {
+                sc.add_token_to_auction(STARTING_AUCTION_ID);
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let auction = sc.get_auction_stats(STARTING_AUCTION_ID).auction;
+
+            assert_eq!(auction.current_quantity, managed_biguint!(3));
+            assert_eq!(auction.max_quantity, managed_biguint!(3));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn add_token_to_bundle_auction_fails_if_incomplete() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_bundle_auction(&mut setup, 1);
+    set_owner_bundle_balances(&mut setup, 2);
+
+    let mut deposit = bundle_deposit(2);
+    deposit[1].value = rust_biguint!(3);
+
+    setup
+        .blockchain_wrapper
+        .execute_esdt_multi_transfer(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &deposit,
+            |sc| {
+                sc.add_token_to_auction(STARTING_AUCTION_ID);
+            },
+        )
+        .assert_user_error(ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH);
+}
+
+#[test]
+fn retire_bundles_sends_every_component() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_bundle_auction(&mut setup, 3);
+
+    setup.retire_auction(STARTING_AUCTION_ID, 2);
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.owner_address,
+        PENGUIN_TOKEN,
+        PENGUIN_NONCE,
+        &rust_biguint!(2),
+        Option::Some(&BoxedBytes::empty()),
+    );
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.owner_address,
+        ITEM_TOKEN,
+        ITEM_NONCE,
+        &rust_biguint!(2 * ITEMS_PER_BUNDLE),
+        Option::Some(&BoxedBytes::empty()),
+    );
+}
+
+#[test]
+fn set_honor_royalties_fails_for_bundle_auction() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_bundle_auction(&mut setup, 1);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_honor_royalties(STARTING_AUCTION_ID, true);
+            },
+        )
+        .assert_user_error(ERR_WRONG_AUCTION_KIND);
+}
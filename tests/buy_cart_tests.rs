@@ -0,0 +1,201 @@
+use apc_sales::{
+    EmptyContract, ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH,
+    ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH, ERR_NOT_ALLOWLISTED, ERR_NOT_ENOUGHT_ITEMS,
+    STARTING_AUCTION_ID,
+};
+use multiversx_sc::types::{BoxedBytes, MultiValueEncoded};
+use multiversx_sc_scenario::{managed_biguint, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+const FIRST_PRICE: u64 = 100;
+const SECOND_PRICE: u64 = 40;
+const OTHER_OUTPUT_TOKEN: &[u8] = b"ITEM-bbbbbb";
+const OTHER_OUTPUT_NONCE: u64 = 3;
+const QUANTITY: u64 = 10;
+
+fn create_auctions<ContractObjBuilder>(setup: &mut helpers::ContractSetup<ContractObjBuilder>)
+where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup.create_default_auction_buyable_in_egld(FIRST_PRICE, 0, QUANTITY);
+    setup.create_auction_buyable_in_egld(
+        OTHER_OUTPUT_TOKEN,
+        OTHER_OUTPUT_NONCE,
+        SECOND_PRICE,
+        0,
+        QUANTITY,
+    );
+}
+
+fn buy_cart<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    cart: &[(u64, u64)],
+    payment: u64,
+    expected_error: Option<&str>,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(payment));
+
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.user_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(payment),
+        |sc| {
+            let mut args = MultiValueEncoded::new();
+
+            for (auction_id, quantity) in cart {
+                args.push((*auction_id, managed_biguint!(*quantity)).into());
+            }
+
+            sc.buy_cart(args);
+        },
+    );
+
+    match expected_error {
+        Some(message) => tx_result.assert_user_error(message),
+        None => tx_result.assert_ok(),
+    }
+}
+
+#[test]
+fn buy_cart_delivers_every_item_and_refunds_change() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_auctions(&mut setup);
+
+    buy_cart(
+        &mut setup,
+        &[(STARTING_AUCTION_ID, 1), (STARTING_AUCTION_ID + 1, 2)],
+        FIRST_PRICE + SECOND_PRICE * 2 + 7,
+        None,
+    );
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.user_address,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(1),
+        Option::Some(&BoxedBytes::empty()),
+    );
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.user_address,
+        OTHER_OUTPUT_TOKEN,
+        OTHER_OUTPUT_NONCE,
+        &rust_biguint!(2),
+        Option::Some(&BoxedBytes::empty()),
+    );
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&setup.user_address, &rust_biguint!(7));
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID + 1),
+                managed_biguint!(SECOND_PRICE * 2)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_cart_error_identifies_the_auction() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_auctions(&mut setup);
+
+    buy_cart(
+        &mut setup,
+        &[(STARTING_AUCTION_ID, 1), (STARTING_AUCTION_ID + 1, 2)],
+        FIRST_PRICE + SECOND_PRICE,
+        Some(&format!(
+            "Auction {}: {}",
+            STARTING_AUCTION_ID + 1,
+            ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH
+        )),
+    );
+
+    buy_cart(
+        &mut setup,
+        &[(STARTING_AUCTION_ID, QUANTITY + 1)],
+        FIRST_PRICE * (QUANTITY + 1),
+        Some(&format!(
+            "Auction {}: {}",
+            STARTING_AUCTION_ID, ERR_NOT_ENOUGHT_ITEMS
+        )),
+    );
+
+    // nothing was sold
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.user_address,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(0),
+        Option::Some(&BoxedBytes::empty()),
+    );
+}
+
+#[test]
+fn buy_cart_refuses_auctions_not_priced_in_egld() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_auction_buyable_in_esdt(
+        b"USDC-aaaaaa",
+        0,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        FIRST_PRICE,
+        0,
+        QUANTITY,
+    );
+
+    buy_cart(
+        &mut setup,
+        &[(STARTING_AUCTION_ID, 1)],
+        FIRST_PRICE,
+        Some(&format!(
+            "Auction {}: {}",
+            STARTING_AUCTION_ID, ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH
+        )),
+    );
+}
+
+#[test]
+fn buy_cart_follows_the_presale_of_the_auction() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_auctions(&mut setup);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_presale(
+                    STARTING_AUCTION_ID + 1,
+                    100,
+                    managed_biguint!(0),
+                    managed_biguint!(0),
+                );
+            },
+        )
+        .assert_ok();
+
+    buy_cart(
+        &mut setup,
+        &[(STARTING_AUCTION_ID, 1), (STARTING_AUCTION_ID + 1, 1)],
+        FIRST_PRICE + SECOND_PRICE,
+        Some(&format!(
+            "Auction {}: {}",
+            STARTING_AUCTION_ID + 1,
+            ERR_NOT_ALLOWLISTED
+        )),
+    );
+}
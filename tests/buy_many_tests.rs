@@ -0,0 +1,219 @@
+use apc_sales::{
+    EmptyContract, ERR_BUY_MANY_MISSING_PAYMENT, ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH,
+    STARTING_AUCTION_ID,
+};
+use multiversx_sc::types::{BoxedBytes, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    managed_biguint, rust_biguint, testing_framework::TxTokenTransfer, DebugApi,
+};
+
+use crate::helpers;
+
+const COMMUNITY_TOKEN: &[u8] = b"COMM-aaaaaa";
+const COMMUNITY_PRICE: u64 = 10;
+const STABLECOIN_TOKEN: &[u8] = b"USDC-aaaaaa";
+const STABLECOIN_PRICE: u64 = 20;
+const OTHER_OUTPUT_TOKEN: &[u8] = b"ITEM-bbbbbb";
+const OTHER_OUTPUT_NONCE: u64 = 3;
+const QUANTITY: u64 = 10;
+
+/** The first auction is priced in the community token, the second one in the stablecoin */
+fn create_auctions<ContractObjBuilder>(setup: &mut helpers::ContractSetup<ContractObjBuilder>)
+where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup.create_auction_buyable_in_esdt(
+        COMMUNITY_TOKEN,
+        0,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        COMMUNITY_PRICE,
+        0,
+        QUANTITY,
+    );
+    setup.create_auction_buyable_in_esdt(
+        STABLECOIN_TOKEN,
+        0,
+        OTHER_OUTPUT_TOKEN,
+        OTHER_OUTPUT_NONCE,
+        STABLECOIN_PRICE,
+        0,
+        QUANTITY,
+    );
+}
+
+fn buy_many<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    purchases: &[(u64, u64)],
+    community_payment: u64,
+    stablecoin_payment: u64,
+    expected_error: Option<&str>,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup.blockchain_wrapper.set_esdt_balance(
+        &setup.user_address,
+        COMMUNITY_TOKEN,
+        &rust_biguint!(community_payment),
+    );
+    setup.blockchain_wrapper.set_esdt_balance(
+        &setup.user_address,
+        STABLECOIN_TOKEN,
+        &rust_biguint!(stablecoin_payment),
+    );
+
+    let payments: Vec<_> = [
+        (COMMUNITY_TOKEN, community_payment),
+        (STABLECOIN_TOKEN, stablecoin_payment),
+    ]
+    .iter()
+    .filter(|(_, amount)| *amount > 0)
+    .map(|(token_identifier, amount)| TxTokenTransfer {
+        token_identifier: token_identifier.to_vec(),
+        nonce: 0,
+        value: rust_biguint!(*amount),
+    })
+    .collect();
+
+    let tx_result = setup.blockchain_wrapper.execute_esdt_multi_transfer(
+        &setup.user_address,
+        &setup.contract_wrapper,
+        &payments,
+        |sc| {
+            let mut args = MultiValueEncoded::new();
+
+            for (auction_id, quantity) in purchases {
+                args.push((*auction_id, managed_biguint!(*quantity)).into());
+            }
+
+            sc.buy_many(args);
+        },
+    );
+
+    match expected_error {
+        Some(message) => tx_result.assert_user_error(message),
+        None => tx_result.assert_ok(),
+    }
+}
+
+#[test]
+fn buy_many_delivers_items_and_refunds_remainder() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_auctions(&mut setup);
+
+    buy_many(
+        &mut setup,
+        &[(STARTING_AUCTION_ID, 2), (STARTING_AUCTION_ID + 1, 3)],
+        COMMUNITY_PRICE * 2 + 5,
+        STABLECOIN_PRICE * 3,
+        None,
+    );
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.user_address,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(2),
+        Option::Some(&BoxedBytes::empty()),
+    );
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.user_address,
+        OTHER_OUTPUT_TOKEN,
+        OTHER_OUTPUT_NONCE,
+        &rust_biguint!(3),
+        Option::Some(&BoxedBytes::empty()),
+    );
+    setup.blockchain_wrapper.check_esdt_balance(
+        &setup.user_address,
+        COMMUNITY_TOKEN,
+        &rust_biguint!(5),
+    );
+    setup.blockchain_wrapper.check_esdt_balance(
+        &setup.user_address,
+        STABLECOIN_TOKEN,
+        &rust_biguint!(0),
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID),
+                managed_biguint!(COMMUNITY_PRICE * 2)
+            );
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID + 1),
+                managed_biguint!(STABLECOIN_PRICE * 3)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_many_checks_the_total_owed_per_token() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_auctions(&mut setup);
+
+    // both purchases of the first auction are paid with the same community tokens
+    buy_many(
+        &mut setup,
+        &[(STARTING_AUCTION_ID, 2), (STARTING_AUCTION_ID, 2)],
+        COMMUNITY_PRICE * 3,
+        0,
+        Some(ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH),
+    );
+}
+
+#[test]
+fn buy_many_reverts_every_purchase_on_failure() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_auctions(&mut setup);
+
+    buy_many(
+        &mut setup,
+        &[(STARTING_AUCTION_ID, 2), (STARTING_AUCTION_ID + 1, 1)],
+        COMMUNITY_PRICE * 2,
+        STABLECOIN_PRICE - 1,
+        Some(ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH),
+    );
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.user_address,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(0),
+        Option::Some(&BoxedBytes::empty()),
+    );
+    setup.blockchain_wrapper.check_esdt_balance(
+        &setup.user_address,
+        COMMUNITY_TOKEN,
+        &rust_biguint!(COMMUNITY_PRICE * 2),
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let auction = sc.auctions(STARTING_AUCTION_ID).get();
+
+            assert_eq!(auction.current_quantity, managed_biguint!(QUANTITY));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_many_fails_without_accepted_payment() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_auctions(&mut setup);
+
+    buy_many(
+        &mut setup,
+        &[(STARTING_AUCTION_ID + 1, 1)],
+        COMMUNITY_PRICE,
+        0,
+        Some(ERR_BUY_MANY_MISSING_PAYMENT),
+    );
+}
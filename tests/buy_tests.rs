@@ -1,10 +1,12 @@
 use apc_sales::{
     EmptyContract, ERR_INVALID_AUCTION_ID, ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH,
     ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH, ERR_INVALID_PAYMENT_TOKEN_NONCE_MISMATCH,
-    ERR_NOT_ENOUGHT_ITEMS, ERR_SALE_IS_NOT_OPENED_YET, STARTING_AUCTION_ID,
+    ERR_MAX_PER_WALLET_EXCEEDED, ERR_NOT_ENOUGHT_ITEMS, ERR_SALE_IS_CLOSED,
+    ERR_SALE_IS_NOT_OPENED_YET, STARTING_AUCTION_ID,
 };
+use multiversx_sc::codec::multi_types::OptionalValue;
 use multiversx_sc::types::BoxedBytes;
-use multiversx_sc_scenario::rust_biguint;
+use multiversx_sc_scenario::{managed_biguint, rust_biguint};
 
 use crate::helpers;
 
@@ -41,12 +43,70 @@ fn buy_fail_if_locked() {
             &setup.contract_wrapper,
             &rust_biguint!(PRICE * QUANTITY),
             |sc| {
-                sc.buy(STARTING_AUCTION_ID);
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_user_error(ERR_SALE_IS_NOT_OPENED_YET);
 }
 
+#[test]
+fn buy_fail_if_closed() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 50;
+    const QUANTITY: u64 = 1;
+    const START_TIMESTAMP: u64 = 10;
+    const END_TIMESTAMP: u64 = 20;
+
+    setup.create_default_timed_auction_buyable_in_egld(
+        PRICE,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(PRICE * QUANTITY));
+
+    setup.blockchain_wrapper.set_block_timestamp(END_TIMESTAMP);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(PRICE * QUANTITY),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_SALE_IS_CLOSED);
+}
+
+#[test]
+fn buy_before_end_successful() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 50;
+    const QUANTITY: u64 = 1;
+    const END_TIMESTAMP: u64 = 20;
+
+    setup.create_default_timed_auction_buyable_in_egld(PRICE, 0, END_TIMESTAMP, QUANTITY);
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(END_TIMESTAMP - 1);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * QUANTITY);
+}
+
 #[test]
 fn buy_fail_wrong_amount_sent() {
     let mut setup = helpers::setup_contract(apc_sales::contract_obj);
@@ -69,7 +129,11 @@ fn buy_fail_wrong_amount_sent() {
             &setup.contract_wrapper,
             &rust_biguint!(ACTUAL_PRICE),
             |sc| {
-                sc.buy(STARTING_AUCTION_ID);
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_user_error(ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH);
@@ -103,7 +167,13 @@ fn buy_fail_wrong_token_sent() {
             OUTPUT_TOKEN_ID,
             OUTPUT_TOKEN_NONCE,
             &rust_biguint!(PRICE),
-            |sc| sc.buy(STARTING_AUCTION_ID),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                )
+            },
         )
         .assert_user_error(ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH);
 }
@@ -148,7 +218,13 @@ fn buy_fail_wrong_nonce_sent() {
             MONEY_TOKEN,
             MONEY_NONCE_SENT,
             &rust_biguint!(PRICE),
-            |sc| sc.buy(STARTING_AUCTION_ID),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                )
+            },
         )
         .assert_user_error(ERR_INVALID_PAYMENT_TOKEN_NONCE_MISMATCH);
 }
@@ -176,12 +252,85 @@ fn buy_fail_if_not_enough_quantity_remaining() {
             &setup.contract_wrapper,
             &rust_biguint!(PRICE * BUY_QUANTITY),
             |sc| {
-                sc.buy(STARTING_AUCTION_ID);
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_user_error(ERR_NOT_ENOUGHT_ITEMS);
 }
 
+#[test]
+fn buy_fail_if_max_per_wallet_exceeded() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 50;
+    const QUANTITY: u64 = 10;
+    const MAX_PER_WALLET: u64 = 3;
+
+    setup.create_default_limited_auction_buyable_in_egld(PRICE, QUANTITY, MAX_PER_WALLET);
+
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * 2);
+
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(PRICE * 2));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(PRICE * 2),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_MAX_PER_WALLET_EXCEEDED);
+
+    // the remaining allowance can still be bought
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+}
+
+#[test]
+fn buy_max_per_wallet_is_per_address() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 50;
+    const QUANTITY: u64 = 10;
+    const MAX_PER_WALLET: u64 = 3;
+
+    setup.create_default_limited_auction_buyable_in_egld(PRICE, QUANTITY, MAX_PER_WALLET);
+
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * MAX_PER_WALLET);
+
+    let other_user = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(PRICE * MAX_PER_WALLET));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &other_user,
+            &setup.contract_wrapper,
+            &rust_biguint!(PRICE * MAX_PER_WALLET),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+}
+
 #[test]
 fn buy_fails_if_unexisting_auction() {
     let mut setup = helpers::setup_contract(apc_sales::contract_obj);
@@ -202,7 +351,11 @@ fn buy_fails_if_unexisting_auction() {
             &setup.contract_wrapper,
             &rust_biguint!(PRICE * 1),
             |sc| {
-                sc.buy(UNEXISTING_AUCTION_ID);
+                sc.buy(
+                    UNEXISTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_user_error(ERR_INVALID_AUCTION_ID);
@@ -225,7 +378,94 @@ fn buy_with_0_egld_amount() {
             &setup.contract_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.buy(STARTING_AUCTION_ID);
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH);
+}
+
+#[test]
+fn buy_with_quantity_refunds_excess() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 50;
+    const QUANTITY: u64 = 2;
+    const PAYMENT: u64 = 130;
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, 10);
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(PAYMENT));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(PAYMENT),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::Some(managed_biguint!(QUANTITY)),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    setup.blockchain_wrapper.check_egld_balance(
+        &setup.user_address,
+        &rust_biguint!(PAYMENT - PRICE * QUANTITY),
+    );
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.user_address,
+        crate::helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        crate::helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(QUANTITY),
+        Option::Some(&BoxedBytes::empty()),
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID),
+                PRICE * QUANTITY
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_with_quantity_fails_if_payment_too_low() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 50;
+    const QUANTITY: u64 = 2;
+    const PAYMENT: u64 = PRICE * QUANTITY - 1;
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, 10);
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(PAYMENT));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(PAYMENT),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::Some(managed_biguint!(QUANTITY)),
+                    OptionalValue::None,
+                );
             },
         )
         .assert_user_error(ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH);
@@ -249,7 +489,11 @@ fn buy_n_succesfully(quantity: u64) {
             &setup.contract_wrapper,
             &rust_biguint!(PRICE * quantity),
             |sc| {
-                sc.buy(STARTING_AUCTION_ID);
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_ok();
@@ -1,9 +1,11 @@
 use apc_sales::{
-    auction::Auction, EmptyContract, ERR_CREATE_AUCTION_BAD_EGLD_NONCE,
+    auction::{Auction, AuctionStatus},
+    EmptyContract, ERR_CREATE_AUCTION_BAD_EGLD_NONCE, ERR_CREATE_AUCTION_BAD_END_TIMESTAMP,
     ERR_CREATE_AUCTION_BAD_PRICE, STARTING_AUCTION_ID,
 };
+use multiversx_sc::codec::multi_types::OptionalValue;
 use multiversx_sc::types::{BoxedBytes, EgldOrEsdtTokenIdentifier, TokenIdentifier};
-use multiversx_sc_scenario::{managed_biguint, rust_biguint, DebugApi};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint, DebugApi};
 
 use crate::helpers;
 
@@ -26,6 +28,8 @@ fn create_auction() {
         QUANTITY,
     );
 
+    let owner_address = setup.owner_address.clone();
+
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
@@ -38,10 +42,14 @@ fn create_auction() {
                     output_token_nonce: OUTPUT_TOKEN_NONCE,
                     price: managed_biguint!(PRICE),
                     start_timestamp: START_TIMESTAMP,
+                    end_timestamp: 0,
                     input_token_id: EgldOrEsdtTokenIdentifier::egld(),
                     input_token_nonce: 0,
                     max_quantity: managed_biguint!(QUANTITY),
-                    current_quantity: managed_biguint!(QUANTITY)
+                    current_quantity: managed_biguint!(QUANTITY),
+                    max_per_wallet: managed_biguint!(0),
+                    status: AuctionStatus::Active,
+                    seller: managed_address!(&owner_address),
                 }
             );
         })
@@ -69,7 +77,15 @@ fn create_auction_fails_if_egld_nonce_is_wrong() {
             helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
             &rust_biguint!(0),
             |sc| {
-                sc.create_auction(EgldOrEsdtTokenIdentifier::egld(), 1, managed_biguint!(1), 0);
+                sc.create_auction(
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    1,
+                    managed_biguint!(1),
+                    0,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_user_error(ERR_CREATE_AUCTION_BAD_EGLD_NONCE);
@@ -96,8 +112,37 @@ fn create_auction_fails_if_price_equals_0() {
             helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
             &rust_biguint!(0),
             |sc| {
-                sc.create_auction(EgldOrEsdtTokenIdentifier::egld(), 0, managed_biguint!(0), 0);
+                sc.create_auction(
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    0,
+                    managed_biguint!(0),
+                    0,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_user_error(ERR_CREATE_AUCTION_BAD_PRICE);
 }
+
+#[test]
+fn create_auction_fails_if_end_before_start() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const START_TIMESTAMP: u64 = 10;
+
+    setup
+        .create_default_auction_with(5, |sc| {
+            sc.create_auction(
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                managed_biguint!(1),
+                START_TIMESTAMP,
+                OptionalValue::Some(START_TIMESTAMP),
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error(ERR_CREATE_AUCTION_BAD_END_TIMESTAMP);
+}
@@ -0,0 +1,232 @@
+use apc_sales::{
+    EmptyContract, ERR_CREATE_AUCTION_BAD_FLOOR_PRICE, ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH,
+    ERR_SALE_IS_CLOSED, STARTING_AUCTION_ID,
+};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{BoxedBytes, EgldOrEsdtTokenIdentifier};
+use multiversx_sc_scenario::{managed_biguint, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+const START_PRICE: u64 = 100;
+const FLOOR_PRICE: u64 = 50;
+const START_TIMESTAMP: u64 = 1_000;
+const END_TIMESTAMP: u64 = 1_100;
+const QUANTITY: u64 = 10;
+
+fn check_current_price<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    timestamp: u64,
+    expected_price: u64,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup.blockchain_wrapper.set_block_timestamp(timestamp);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_current_price(STARTING_AUCTION_ID), expected_price);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn linear_price_goes_down_to_floor() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_dutch_auction_in_egld(
+        START_PRICE,
+        FLOOR_PRICE,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        0,
+        QUANTITY,
+    );
+
+    check_current_price(&mut setup, 0, START_PRICE);
+    check_current_price(&mut setup, START_TIMESTAMP, START_PRICE);
+    check_current_price(&mut setup, START_TIMESTAMP + 10, 95);
+    check_current_price(&mut setup, START_TIMESTAMP + 50, 75);
+    check_current_price(&mut setup, END_TIMESTAMP, FLOOR_PRICE);
+    check_current_price(&mut setup, END_TIMESTAMP + 1_000, FLOOR_PRICE);
+}
+
+#[test]
+fn stepped_price_goes_down_by_steps() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const STEP_DURATION: u64 = 25;
+
+    setup.create_default_dutch_auction_in_egld(
+        START_PRICE,
+        FLOOR_PRICE,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        STEP_DURATION,
+        QUANTITY,
+    );
+
+    check_current_price(&mut setup, START_TIMESTAMP + 24, START_PRICE);
+    check_current_price(&mut setup, START_TIMESTAMP + 25, 88);
+    check_current_price(&mut setup, START_TIMESTAMP + 49, 88);
+    check_current_price(&mut setup, START_TIMESTAMP + 50, 75);
+    check_current_price(&mut setup, END_TIMESTAMP - 1, 63);
+    check_current_price(&mut setup, END_TIMESTAMP, FLOOR_PRICE);
+}
+
+#[test]
+fn buy_charges_current_price_and_refunds_change() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const CURRENT_PRICE: u64 = 75;
+    const PAYMENT: u64 = 200;
+    const BOUGHT_QUANTITY: u64 = 2;
+
+    setup.create_default_dutch_auction_in_egld(
+        START_PRICE,
+        FLOOR_PRICE,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        0,
+        QUANTITY,
+    );
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(START_TIMESTAMP + 50);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PAYMENT);
+
+    setup.blockchain_wrapper.check_egld_balance(
+        &setup.user_address,
+        &rust_biguint!(PAYMENT - CURRENT_PRICE * BOUGHT_QUANTITY),
+    );
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.user_address,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(BOUGHT_QUANTITY),
+        Option::Some(&BoxedBytes::empty()),
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID),
+                CURRENT_PRICE * BOUGHT_QUANTITY
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_fails_if_payment_below_current_price() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_dutch_auction_in_egld(
+        START_PRICE,
+        FLOOR_PRICE,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        0,
+        QUANTITY,
+    );
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(START_TIMESTAMP);
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(START_PRICE - 1));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(START_PRICE - 1),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                )
+            },
+        )
+        .assert_user_error(ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH);
+}
+
+#[test]
+fn create_dutch_auction_fails_if_floor_above_start_price() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup
+        .create_default_auction_with(QUANTITY, |sc| {
+            sc.create_dutch_auction(
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                managed_biguint!(FLOOR_PRICE),
+                managed_biguint!(START_PRICE),
+                START_TIMESTAMP,
+                END_TIMESTAMP,
+                0,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error(ERR_CREATE_AUCTION_BAD_FLOOR_PRICE);
+}
+
+#[test]
+fn buy_fails_after_end_timestamp() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let end_timestamp = END_TIMESTAMP + 100;
+
+    setup
+        .create_default_auction_with(QUANTITY, |sc| {
+            sc.create_dutch_auction(
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                managed_biguint!(START_PRICE),
+                managed_biguint!(FLOOR_PRICE),
+                START_TIMESTAMP,
+                END_TIMESTAMP,
+                0,
+                OptionalValue::Some(end_timestamp),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    setup.blockchain_wrapper.set_block_timestamp(end_timestamp);
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(FLOOR_PRICE));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(FLOOR_PRICE),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                )
+            },
+        )
+        .assert_user_error(ERR_SALE_IS_CLOSED);
+}
+
+#[test]
+fn current_price_of_fixed_price_auction_is_its_price() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(START_PRICE, 0, QUANTITY);
+
+    check_current_price(&mut setup, END_TIMESTAMP, START_PRICE);
+}
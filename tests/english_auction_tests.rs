@@ -0,0 +1,424 @@
+use apc_sales::{
+    auction::{AuctionKind, EnglishAuction},
+    EmptyContract, ERR_AUCTION_ALREADY_CLAIMED, ERR_AUCTION_HAS_BIDS, ERR_BIDDING_IS_NOT_OVER,
+    ERR_BIDDING_IS_OVER, ERR_BID_TOO_LOW, ERR_CREATE_AUCTION_BAD_BID_INCREMENT,
+    ERR_CREATE_AUCTION_BAD_END_TIMESTAMP, ERR_WRONG_AUCTION_KIND, STARTING_AUCTION_ID,
+};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{BoxedBytes, EgldOrEsdtTokenIdentifier, ManagedAddress};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+const RESERVE_PRICE: u64 = 100;
+const MIN_BID_INCREMENT: u64 = 10;
+const START_TIMESTAMP: u64 = 0;
+const END_TIMESTAMP: u64 = 1_000;
+const QUANTITY: u64 = 1;
+
+#[test]
+fn create_english_auction_works() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_english_auction_in_egld(
+        RESERVE_PRICE,
+        MIN_BID_INCREMENT,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let auction_stats = sc.get_auction_stats(STARTING_AUCTION_ID);
+
+            assert_eq!(auction_stats.kind, AuctionKind::English);
+            assert_eq!(auction_stats.auction.price, RESERVE_PRICE);
+            assert_eq!(auction_stats.auction.end_timestamp, END_TIMESTAMP);
+
+            assert_eq!(
+                sc.get_english_auction_view(STARTING_AUCTION_ID),
+                EnglishAuction {
+                    reserve_price: managed_biguint!(RESERVE_PRICE),
+                    min_bid_increment: managed_biguint!(MIN_BID_INCREMENT),
+                    highest_bid: managed_biguint!(0),
+                    highest_bidder: ManagedAddress::<DebugApi>::zero(),
+                    claimed: false,
+                }
+            );
+
+            assert_eq!(sc.get_minimum_bid(STARTING_AUCTION_ID), RESERVE_PRICE);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn create_english_auction_fails_if_end_before_start() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup
+        .create_default_auction_with(QUANTITY, |sc| {
+            sc.create_english_auction(
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                managed_biguint!(RESERVE_PRICE),
+                managed_biguint!(MIN_BID_INCREMENT),
+                END_TIMESTAMP,
+                END_TIMESTAMP,
+            );
+        })
+        .assert_user_error(ERR_CREATE_AUCTION_BAD_END_TIMESTAMP);
+}
+
+#[test]
+fn create_english_auction_fails_if_increment_is_0() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup
+        .create_default_auction_with(QUANTITY, |sc| {
+            sc.create_english_auction(
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                managed_biguint!(RESERVE_PRICE),
+                managed_biguint!(0),
+                START_TIMESTAMP,
+                END_TIMESTAMP,
+            );
+        })
+        .assert_user_error(ERR_CREATE_AUCTION_BAD_BID_INCREMENT);
+}
+
+#[test]
+fn bid_fails_if_below_reserve_price() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_english_auction_in_egld(
+        RESERVE_PRICE,
+        MIN_BID_INCREMENT,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(RESERVE_PRICE - 1));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(RESERVE_PRICE - 1),
+            |sc| sc.bid(STARTING_AUCTION_ID),
+        )
+        .assert_user_error(ERR_BID_TOO_LOW);
+}
+
+#[test]
+fn bid_fails_if_below_increment() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let other_bidder = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    setup.create_default_english_auction_in_egld(
+        RESERVE_PRICE,
+        MIN_BID_INCREMENT,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+
+    let user_address = setup.user_address.clone();
+    setup.bid_in_egld(&user_address, STARTING_AUCTION_ID, RESERVE_PRICE);
+
+    const TOO_LOW_BID: u64 = RESERVE_PRICE + MIN_BID_INCREMENT - 1;
+
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&other_bidder, &rust_biguint!(TOO_LOW_BID));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &other_bidder,
+            &setup.contract_wrapper,
+            &rust_biguint!(TOO_LOW_BID),
+            |sc| sc.bid(STARTING_AUCTION_ID),
+        )
+        .assert_user_error(ERR_BID_TOO_LOW);
+}
+
+#[test]
+fn outbid_refunds_previous_bidder() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let other_bidder = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    const SECOND_BID: u64 = RESERVE_PRICE + MIN_BID_INCREMENT;
+
+    setup.create_default_english_auction_in_egld(
+        RESERVE_PRICE,
+        MIN_BID_INCREMENT,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+
+    let user_address = setup.user_address.clone();
+    setup.bid_in_egld(&user_address, STARTING_AUCTION_ID, RESERVE_PRICE);
+    setup.bid_in_egld(&other_bidder, STARTING_AUCTION_ID, SECOND_BID);
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&setup.user_address, &rust_biguint!(RESERVE_PRICE));
+
+    setup.blockchain_wrapper.check_egld_balance(
+        &setup.contract_wrapper.address_ref(),
+        &rust_biguint!(SECOND_BID),
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let english_auction = sc.get_english_auction_view(STARTING_AUCTION_ID);
+
+            assert_eq!(english_auction.highest_bid, SECOND_BID);
+            assert_eq!(
+                english_auction.highest_bidder,
+                managed_address!(&other_bidder)
+            );
+            assert_eq!(
+                sc.get_minimum_bid(STARTING_AUCTION_ID),
+                SECOND_BID + MIN_BID_INCREMENT
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn bid_fails_after_end() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_english_auction_in_egld(
+        RESERVE_PRICE,
+        MIN_BID_INCREMENT,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+
+    setup.blockchain_wrapper.set_block_timestamp(END_TIMESTAMP);
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(RESERVE_PRICE));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(RESERVE_PRICE),
+            |sc| sc.bid(STARTING_AUCTION_ID),
+        )
+        .assert_user_error(ERR_BIDDING_IS_OVER);
+}
+
+#[test]
+fn bid_fails_on_fixed_price_auction() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(RESERVE_PRICE, START_TIMESTAMP, QUANTITY);
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(RESERVE_PRICE));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(RESERVE_PRICE),
+            |sc| sc.bid(STARTING_AUCTION_ID),
+        )
+        .assert_user_error(ERR_WRONG_AUCTION_KIND);
+}
+
+#[test]
+fn buy_fails_on_english_auction() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_english_auction_in_egld(
+        RESERVE_PRICE,
+        MIN_BID_INCREMENT,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(RESERVE_PRICE));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(RESERVE_PRICE),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                )
+            },
+        )
+        .assert_user_error(ERR_WRONG_AUCTION_KIND);
+}
+
+#[test]
+fn retire_fails_if_auction_has_bids() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_english_auction_in_egld(
+        RESERVE_PRICE,
+        MIN_BID_INCREMENT,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+
+    let user_address = setup.user_address.clone();
+    setup.bid_in_egld(&user_address, STARTING_AUCTION_ID, RESERVE_PRICE);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.retire_token_from_auction(STARTING_AUCTION_ID, &managed_biguint!(QUANTITY)),
+        )
+        .assert_user_error(ERR_AUCTION_HAS_BIDS);
+}
+
+#[test]
+fn claim_fails_before_end() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_english_auction_in_egld(
+        RESERVE_PRICE,
+        MIN_BID_INCREMENT,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.claim(STARTING_AUCTION_ID),
+        )
+        .assert_user_error(ERR_BIDDING_IS_NOT_OVER);
+}
+
+#[test]
+fn claim_sends_lot_to_winner_and_proceeds_to_owner() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_english_auction_in_egld(
+        RESERVE_PRICE,
+        MIN_BID_INCREMENT,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+
+    let user_address = setup.user_address.clone();
+    setup.bid_in_egld(&user_address, STARTING_AUCTION_ID, RESERVE_PRICE);
+
+    setup.blockchain_wrapper.set_block_timestamp(END_TIMESTAMP);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.claim(STARTING_AUCTION_ID),
+        )
+        .assert_ok();
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.user_address,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(QUANTITY),
+        Option::Some(&BoxedBytes::empty()),
+    );
+
+    setup.withdraw_balance();
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&setup.owner_address, &rust_biguint!(RESERVE_PRICE));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.claim(STARTING_AUCTION_ID),
+        )
+        .assert_user_error(ERR_AUCTION_ALREADY_CLAIMED);
+}
+
+#[test]
+fn claim_without_bids_returns_lot_to_owner() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_english_auction_in_egld(
+        RESERVE_PRICE,
+        MIN_BID_INCREMENT,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+
+    setup.blockchain_wrapper.set_block_timestamp(END_TIMESTAMP);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.claim(STARTING_AUCTION_ID),
+        )
+        .assert_ok();
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.owner_address,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(QUANTITY),
+        Option::Some(&BoxedBytes::empty()),
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let auction_stats = sc.get_auction_stats(STARTING_AUCTION_ID);
+
+            assert_eq!(auction_stats.auction.current_quantity, 0);
+            assert_eq!(auction_stats.auction.max_quantity, 0);
+        })
+        .assert_ok();
+}
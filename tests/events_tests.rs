@@ -0,0 +1,266 @@
+use apc_sales::{EmptyContract, STARTING_AUCTION_ID};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::codec::{top_encode_to_vec_u8_or_panic, TopEncode};
+use multiversx_sc::types::{BoxedBytes, EgldOrEsdtTokenIdentifier};
+use multiversx_sc_scenario::whitebox::TxContextStack;
+use multiversx_sc_scenario::{managed_biguint, rust_biguint};
+
+use crate::helpers;
+
+fn topic<T: TopEncode>(value: T) -> Vec<u8> {
+    top_encode_to_vec_u8_or_panic(&value)
+}
+
+/** Returns the topics of the events logged so far by the running transaction */
+fn emitted_topics() -> Vec<Vec<Vec<u8>>> {
+    TxContextStack::static_peek()
+        .result_borrow_mut()
+        .result_logs
+        .iter()
+        .map(|log| log.topics.clone())
+        .collect()
+}
+
+#[test]
+fn create_auction_emits_event() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 50;
+    const QUANTITY: u64 = 10;
+
+    let mut logged_topics = Vec::new();
+    let tx_result = setup.create_default_auction_with(QUANTITY, |sc| {
+        let _ = sc.create_auction(
+            EgldOrEsdtTokenIdentifier::egld(),
+            0,
+            managed_biguint!(PRICE),
+            0,
+            OptionalValue::None,
+            OptionalValue::None,
+            OptionalValue::None,
+        );
+        logged_topics = emitted_topics();
+    });
+    tx_result.assert_ok();
+
+    let expected_topics = vec![
+        b"createAuction".to_vec(),
+        topic(STARTING_AUCTION_ID),
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN.to_vec(),
+        topic(helpers::DEFAULT_AUCTION_OUTPUT_NONCE),
+        b"EGLD".to_vec(),
+        topic(0u64),
+        topic(PRICE),
+        topic(QUANTITY),
+    ];
+
+    assert!(logged_topics.contains(&expected_topics));
+}
+
+#[test]
+fn add_token_to_auction_emits_event() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const ADDED_QUANTITY: u64 = 5;
+
+    setup.create_default_auction_buyable_in_egld(1, 0, 100);
+
+    setup.blockchain_wrapper.set_nft_balance(
+        &setup.owner_address,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(ADDED_QUANTITY),
+        &BoxedBytes::empty(),
+    );
+
+    let mut logged_topics = Vec::new();
+    let tx_result = setup.blockchain_wrapper.execute_esdt_transfer(
+        &setup.owner_address,
+        &setup.contract_wrapper,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(ADDED_QUANTITY),
+        |sc| {
+            sc.add_token_to_auction(STARTING_AUCTION_ID);
+            logged_topics = emitted_topics();
+        },
+    );
+    tx_result.assert_ok();
+
+    let expected_topics = vec![
+        b"addTokenToAuction".to_vec(),
+        topic(STARTING_AUCTION_ID),
+        topic(ADDED_QUANTITY),
+    ];
+
+    assert!(logged_topics.contains(&expected_topics));
+}
+
+#[test]
+fn retire_token_from_auction_emits_event() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const RETIRED_QUANTITY: u64 = 5;
+
+    setup.create_default_auction_buyable_in_egld(1, 0, 100);
+
+    let mut logged_topics = Vec::new();
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.retire_token_from_auction(STARTING_AUCTION_ID, &managed_biguint!(RETIRED_QUANTITY));
+            logged_topics = emitted_topics();
+        },
+    );
+    tx_result.assert_ok();
+
+    let expected_topics = vec![
+        b"retireTokenFromAuction".to_vec(),
+        topic(STARTING_AUCTION_ID),
+        topic(RETIRED_QUANTITY),
+    ];
+
+    assert!(logged_topics.contains(&expected_topics));
+}
+
+#[test]
+fn hide_auction_emits_event() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(1, 0, 100);
+
+    let mut logged_topics = Vec::new();
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.hide_auction(STARTING_AUCTION_ID);
+            logged_topics = emitted_topics();
+        },
+    );
+    tx_result.assert_ok();
+
+    let expected_topics = vec![b"hideAuction".to_vec(), topic(STARTING_AUCTION_ID)];
+
+    assert!(logged_topics.contains(&expected_topics));
+}
+
+#[test]
+fn buy_emits_event() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 50;
+    const BUY_QUANTITY: u64 = 2;
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, 10);
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(PRICE * BUY_QUANTITY));
+
+    let mut logged_topics = Vec::new();
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.user_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(PRICE * BUY_QUANTITY),
+        |sc| {
+            sc.buy(
+                STARTING_AUCTION_ID,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+            logged_topics = emitted_topics();
+        },
+    );
+    tx_result.assert_ok();
+
+    let expected_topics = vec![
+        b"buy".to_vec(),
+        topic(STARTING_AUCTION_ID),
+        setup.user_address.to_vec(),
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN.to_vec(),
+        topic(helpers::DEFAULT_AUCTION_OUTPUT_NONCE),
+        topic(BUY_QUANTITY),
+        topic(PRICE * BUY_QUANTITY),
+        b"EGLD".to_vec(),
+        topic(0u64),
+    ];
+
+    assert!(logged_topics.contains(&expected_topics));
+}
+
+#[test]
+fn withdraw_balance_emits_event() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 50;
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, 10);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+
+    let mut logged_topics = Vec::new();
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.withdraw_balance();
+            logged_topics = emitted_topics();
+        },
+    );
+    tx_result.assert_ok();
+
+    let expected_topics = vec![
+        b"withdrawBalance".to_vec(),
+        topic(STARTING_AUCTION_ID),
+        setup.owner_address.to_vec(),
+        b"EGLD".to_vec(),
+        topic(0u64),
+        topic(PRICE),
+    ];
+
+    assert!(logged_topics.contains(&expected_topics));
+}
+
+#[test]
+fn update_auction_emits_one_event_per_change() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 50;
+    const NEW_PRICE: u64 = 60;
+    const START_TIMESTAMP: u64 = 10;
+
+    setup.create_default_auction_buyable_in_egld(PRICE, START_TIMESTAMP, 10);
+
+    let mut logged_topics = Vec::new();
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            sc.update_auction(
+                STARTING_AUCTION_ID,
+                managed_biguint!(NEW_PRICE),
+                START_TIMESTAMP,
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+            );
+            logged_topics = emitted_topics();
+        },
+    );
+    tx_result.assert_ok();
+
+    let expected_topics = vec![
+        b"updateAuctionPrice".to_vec(),
+        topic(STARTING_AUCTION_ID),
+        topic(PRICE),
+        topic(NEW_PRICE),
+    ];
+
+    assert!(logged_topics.contains(&expected_topics));
+    assert!(!logged_topics
+        .iter()
+        .any(|topics| topics[0] == b"updateAuctionStartTimestamp".to_vec()));
+}
@@ -0,0 +1,236 @@
+use apc_sales::{auction::FeeShare, EmptyContract, ERR_FEE_SPLITS_BAD_SHARES, STARTING_AUCTION_ID};
+use multiversx_sc::codec::multi_types::MultiValue2;
+use multiversx_sc::types::{Address, EgldOrEsdtTokenIdentifier, ManagedAddress, MultiValueEncoded};
+use multiversx_sc_scenario::{managed_address, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+const PRICE: u64 = 1_000;
+const QUANTITY: u64 = 10;
+
+fn fee_splits_arg(
+    fee_splits: &[(Address, u64)],
+) -> MultiValueEncoded<DebugApi, MultiValue2<ManagedAddress<DebugApi>, u64>> {
+    let mut arg = MultiValueEncoded::new();
+
+    for (payee, bps) in fee_splits {
+        arg.push((managed_address!(payee), *bps).into());
+    }
+
+    return arg;
+}
+
+fn set_default_fee_splits<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    fee_splits: &[(Address, u64)],
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_default_fee_splits(fee_splits_arg(fee_splits));
+            },
+        )
+        .assert_ok();
+}
+
+fn claim_fees<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    payee: &Address,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(payee, &setup.contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.claim_fees();
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_credits_default_fee_splits() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let artist_address = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+    let treasury_address = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    set_default_fee_splits(
+        &mut setup,
+        &[
+            (artist_address.clone(), 1_000),
+            (treasury_address.clone(), 250),
+        ],
+    );
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * 2);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_pending_proceeds(STARTING_AUCTION_ID), 1_750u64);
+
+            let artist_fees: Vec<_> = sc
+                .get_accrued_fees(managed_address!(&artist_address))
+                .into_iter()
+                .map(|accrued_fee| accrued_fee.into_tuple())
+                .collect();
+
+            assert_eq!(artist_fees.len(), 1);
+            assert_eq!(artist_fees[0].0, EgldOrEsdtTokenIdentifier::egld());
+            assert_eq!(artist_fees[0].1, 0);
+            assert_eq!(artist_fees[0].2, 200u64);
+        })
+        .assert_ok();
+
+    claim_fees(&mut setup, &artist_address);
+    claim_fees(&mut setup, &treasury_address);
+    setup.withdraw_balance();
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&artist_address, &rust_biguint!(200));
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&treasury_address, &rust_biguint!(50));
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&setup.owner_address, &rust_biguint!(1_750));
+}
+
+#[test]
+fn auction_fee_splits_replace_default_ones() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let artist_address = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+    let platform_address = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    set_default_fee_splits(&mut setup, &[(platform_address.clone(), 500)]);
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+
+    let auction_fee_splits = [(artist_address.clone(), 2_000)];
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_auction_fee_splits(STARTING_AUCTION_ID, fee_splits_arg(&auction_fee_splits));
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let fee_splits = sc.get_fee_splits(STARTING_AUCTION_ID);
+
+            assert_eq!(fee_splits.len(), 1);
+            assert_eq!(
+                fee_splits.get(0),
+                FeeShare {
+                    payee: managed_address!(&artist_address),
+                    bps: 2_000,
+                }
+            );
+        })
+        .assert_ok();
+
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+
+    claim_fees(&mut setup, &artist_address);
+    claim_fees(&mut setup, &platform_address);
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&artist_address, &rust_biguint!(200));
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&platform_address, &rust_biguint!(0));
+}
+
+#[test]
+fn claim_fees_empties_accrued_fees() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let artist_address = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    set_default_fee_splits(&mut setup, &[(artist_address.clone(), 1_000)]);
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+
+    claim_fees(&mut setup, &artist_address);
+    claim_fees(&mut setup, &artist_address);
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&artist_address, &rust_biguint!(100));
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_accrued_fees(managed_address!(&artist_address))
+                    .into_iter()
+                    .count(),
+                0
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn set_fee_splits_fails_if_shares_exceed_max() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let fee_splits = [
+        (setup.user_address.clone(), 6_000),
+        (setup.owner_address.clone(), 4_001),
+    ];
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_default_fee_splits(fee_splits_arg(&fee_splits));
+            },
+        )
+        .assert_user_error(ERR_FEE_SPLITS_BAD_SHARES);
+}
+
+#[test]
+fn set_fee_splits_fails_if_share_is_zero() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let fee_splits = [(setup.user_address.clone(), 0)];
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_default_fee_splits(fee_splits_arg(&fee_splits));
+            },
+        )
+        .assert_user_error(ERR_FEE_SPLITS_BAD_SHARES);
+}
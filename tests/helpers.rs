@@ -1,4 +1,5 @@
 use apc_sales::*;
+use multiversx_sc::codec::multi_types::OptionalValue;
 use multiversx_sc::types::{Address, BoxedBytes, EgldOrEsdtTokenIdentifier};
 use multiversx_sc_scenario::{
     managed_biguint, managed_token_id_wrapped, rust_biguint, testing_framework::*, DebugApi,
@@ -23,6 +24,59 @@ impl<ContractObjBuilder> ContractSetup<ContractObjBuilder>
 where
     ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
 {
+    /**
+     * Gives `quantity` output tokens to `seller` and deposits them with the creation call of `create_fn`.
+     * The other create helpers are shortcuts of this one.
+     */
+    pub fn create_auction_with<CreateFn>(
+        &mut self,
+        seller: &Address,
+        output_token_id: &[u8],
+        output_token_nonce: u64,
+        quantity: u64,
+        create_fn: CreateFn,
+    ) -> TxResult
+    where
+        CreateFn: FnOnce(apc_sales::ContractObj<DebugApi>),
+    {
+        self.blockchain_wrapper.set_nft_balance(
+            seller,
+            output_token_id,
+            output_token_nonce,
+            &rust_biguint!(quantity),
+            &BoxedBytes::empty(),
+        );
+
+        return self.blockchain_wrapper.execute_esdt_transfer(
+            seller,
+            &self.contract_wrapper,
+            output_token_id,
+            output_token_nonce,
+            &rust_biguint!(quantity),
+            create_fn,
+        );
+    }
+
+    /** Same as `create_auction_with`, for the default output token sold by the owner */
+    pub fn create_default_auction_with<CreateFn>(
+        &mut self,
+        quantity: u64,
+        create_fn: CreateFn,
+    ) -> TxResult
+    where
+        CreateFn: FnOnce(apc_sales::ContractObj<DebugApi>),
+    {
+        let owner_address = self.owner_address.clone();
+
+        return self.create_auction_with(
+            &owner_address,
+            DEFAULT_AUCTION_OUTPUT_TOKEN,
+            DEFAULT_AUCTION_OUTPUT_NONCE,
+            quantity,
+            create_fn,
+        );
+    }
+
     pub fn create_default_auction_buyable_in_egld(
         &mut self,
         price: u64,
@@ -38,6 +92,47 @@ where
         );
     }
 
+    pub fn create_default_timed_auction_buyable_in_egld(
+        &mut self,
+        price: u64,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        quantity: u64,
+    ) {
+        self.create_default_auction_with(quantity, |sc| {
+            let _ = sc.create_auction(
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                managed_biguint!(price),
+                start_timestamp,
+                OptionalValue::Some(end_timestamp),
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+    }
+
+    pub fn create_default_limited_auction_buyable_in_egld(
+        &mut self,
+        price: u64,
+        quantity: u64,
+        max_per_wallet: u64,
+    ) {
+        self.create_default_auction_with(quantity, |sc| {
+            let _ = sc.create_auction(
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                managed_biguint!(price),
+                0,
+                OptionalValue::None,
+                OptionalValue::Some(managed_biguint!(max_per_wallet)),
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+    }
+
     pub fn retire_auction(&mut self, auction_id: u64, amount: u64) {
         self.blockchain_wrapper
             .execute_tx(
@@ -51,41 +146,127 @@ where
             .assert_ok();
     }
 
-    pub fn create_auction_buyable_in_egld(
+    pub fn create_default_english_auction_in_egld(
         &mut self,
-        output_token_id: &[u8],
-        output_token_nonce: u64,
-        price: u64,
+        reserve_price: u64,
+        min_bid_increment: u64,
         start_timestamp: u64,
+        end_timestamp: u64,
         quantity: u64,
     ) {
-        self.blockchain_wrapper.set_nft_balance(
-            &self.owner_address,
-            output_token_id,
-            output_token_nonce,
-            &rust_biguint!(quantity),
-            &BoxedBytes::empty(),
-        );
+        self.create_default_auction_with(quantity, |sc| {
+            let _ = sc.create_english_auction(
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                managed_biguint!(reserve_price),
+                managed_biguint!(min_bid_increment),
+                start_timestamp,
+                end_timestamp,
+            );
+        })
+        .assert_ok();
+    }
+
+    pub fn create_default_dutch_auction_in_egld(
+        &mut self,
+        start_price: u64,
+        floor_price: u64,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        price_step_duration: u64,
+        quantity: u64,
+    ) {
+        self.create_default_auction_with(quantity, |sc| {
+            let _ = sc.create_dutch_auction(
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                managed_biguint!(start_price),
+                managed_biguint!(floor_price),
+                start_timestamp,
+                end_timestamp,
+                price_step_duration,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+    }
+
+    pub fn bid_in_egld(&mut self, bidder: &Address, auction_id: u64, amount: u64) {
+        self.blockchain_wrapper
+            .set_egld_balance(bidder, &rust_biguint!(amount));
+
+        self.blockchain_wrapper
+            .execute_tx(
+                bidder,
+                &self.contract_wrapper,
+                &rust_biguint!(amount),
+                |sc| {
+                    sc.bid(auction_id);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn buy_in_egld(&mut self, auction_id: u64, payment: u64) {
+        self.blockchain_wrapper
+            .set_egld_balance(&self.user_address, &rust_biguint!(payment));
 
         self.blockchain_wrapper
-            .execute_esdt_transfer(
+            .execute_tx(
+                &self.user_address,
+                &self.contract_wrapper,
+                &rust_biguint!(payment),
+                |sc| {
+                    sc.buy(auction_id, OptionalValue::None, OptionalValue::None);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn withdraw_balance(&mut self) {
+        self.blockchain_wrapper
+            .execute_tx(
                 &self.owner_address,
                 &self.contract_wrapper,
-                output_token_id,
-                output_token_nonce,
-                &rust_biguint!(quantity),
+                &rust_biguint!(0),
                 |sc| {
-                    let _ = sc.create_auction(
-                        EgldOrEsdtTokenIdentifier::egld(),
-                        0,
-                        managed_biguint!(price),
-                        start_timestamp,
-                    );
+                    sc.withdraw_balance();
                 },
             )
             .assert_ok();
     }
 
+    pub fn create_auction_buyable_in_egld(
+        &mut self,
+        output_token_id: &[u8],
+        output_token_nonce: u64,
+        price: u64,
+        start_timestamp: u64,
+        quantity: u64,
+    ) {
+        let owner_address = self.owner_address.clone();
+
+        self.create_auction_with(
+            &owner_address,
+            output_token_id,
+            output_token_nonce,
+            quantity,
+            |sc| {
+                let _ = sc.create_auction(
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    0,
+                    managed_biguint!(price),
+                    start_timestamp,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+    }
+
     pub fn create_auction_buyable_in_esdt(
         &mut self,
         input_token_id: &[u8],
@@ -96,31 +277,26 @@ where
         start_timestamp: u64,
         quantity: u64,
     ) {
-        self.blockchain_wrapper.set_nft_balance(
-            &self.owner_address,
+        let owner_address = self.owner_address.clone();
+
+        self.create_auction_with(
+            &owner_address,
             output_token_id,
             output_token_nonce,
-            &rust_biguint!(quantity),
-            &BoxedBytes::empty(),
-        );
-
-        self.blockchain_wrapper
-            .execute_esdt_transfer(
-                &self.owner_address,
-                &self.contract_wrapper,
-                output_token_id,
-                output_token_nonce,
-                &rust_biguint!(quantity),
-                |sc| {
-                    let _ = sc.create_auction(
-                        managed_token_id_wrapped!(input_token_id),
-                        input_token_nonce,
-                        managed_biguint!(price),
-                        start_timestamp,
-                    );
-                },
-            )
-            .assert_ok();
+            quantity,
+            |sc| {
+                let _ = sc.create_auction(
+                    managed_token_id_wrapped!(input_token_id),
+                    input_token_nonce,
+                    managed_biguint!(price),
+                    start_timestamp,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
     }
 }
 
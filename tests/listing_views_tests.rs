@@ -0,0 +1,196 @@
+use apc_sales::{auction::SaleStatus, EmptyContract, STARTING_AUCTION_ID};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, TokenIdentifier};
+use multiversx_sc_scenario::{managed_token_id_wrapped, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+const PRICE: u64 = 10;
+const QUANTITY: u64 = 5;
+const STABLECOIN_TOKEN: &[u8] = b"USDC-aaaaaa";
+const OTHER_OUTPUT_TOKEN: &[u8] = b"ITEM-bbbbbb";
+
+/**
+ * Creates four auctions:
+ * 1. live, in EGLD
+ * 2. sold out, in EGLD
+ * 3. hidden, in EGLD
+ * 4. live, in stablecoin, selling another token
+ */
+fn create_auctions<ContractObjBuilder>(setup: &mut helpers::ContractSetup<ContractObjBuilder>)
+where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+    setup.create_auction_buyable_in_esdt(
+        STABLECOIN_TOKEN,
+        0,
+        OTHER_OUTPUT_TOKEN,
+        1,
+        PRICE,
+        0,
+        QUANTITY,
+    );
+
+    setup.buy_in_egld(STARTING_AUCTION_ID + 1, PRICE * QUANTITY);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.hide_auction(STARTING_AUCTION_ID + 2),
+        )
+        .assert_ok();
+}
+
+#[test]
+fn get_auctions_page_works() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_auctions(&mut setup);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let first_page: Vec<u64> = sc
+                .get_auctions_page(STARTING_AUCTION_ID, 2, OptionalValue::None)
+                .iter()
+                .map(|stats| stats.id)
+                .collect();
+            // the page covers 3 ids, the hidden one included, the next page starts at the 4th one
+            let page_over_hidden: Vec<u64> = sc
+                .get_auctions_page(STARTING_AUCTION_ID, 3, OptionalValue::None)
+                .iter()
+                .map(|stats| stats.id)
+                .collect();
+            let last_page: Vec<u64> = sc
+                .get_auctions_page(STARTING_AUCTION_ID + 2, 2, OptionalValue::None)
+                .iter()
+                .map(|stats| stats.id)
+                .collect();
+            let last_page_with_hidden: Vec<u64> = sc
+                .get_auctions_page(STARTING_AUCTION_ID + 2, 2, OptionalValue::Some(true))
+                .iter()
+                .map(|stats| stats.id)
+                .collect();
+
+            // the hidden auction is left out
+            assert_eq!(first_page, vec![1, 2]);
+            assert_eq!(page_over_hidden, vec![1, 2]);
+            assert_eq!(last_page, vec![4]);
+            assert_eq!(last_page_with_hidden, vec![3, 4]);
+            assert!(sc
+                .get_auctions_page(STARTING_AUCTION_ID + 4, 2, OptionalValue::None)
+                .is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn get_active_auctions_excludes_sold_out_and_hidden() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_auctions(&mut setup);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let active: Vec<u64> = sc
+                .get_active_auctions(0, 10)
+                .iter()
+                .map(|stats| stats.id)
+                .collect();
+            let second_active: Vec<u64> = sc
+                .get_active_auctions(1, 10)
+                .iter()
+                .map(|stats| stats.id)
+                .collect();
+
+            assert_eq!(active, vec![1, 4]);
+            assert_eq!(second_active, vec![4]);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn get_auctions_by_token_works() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_auctions(&mut setup);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let by_output_token: Vec<u64> = sc
+                .get_auctions_by_output_token(
+                    TokenIdentifier::from_esdt_bytes(OTHER_OUTPUT_TOKEN),
+                    0,
+                    10,
+                    OptionalValue::None,
+                )
+                .iter()
+                .map(|stats| stats.id)
+                .collect();
+            let by_egld: Vec<u64> = sc
+                .get_auctions_by_payment_token(
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    0,
+                    10,
+                    OptionalValue::None,
+                )
+                .iter()
+                .map(|stats| stats.id)
+                .collect();
+            let by_stablecoin: Vec<u64> = sc
+                .get_auctions_by_payment_token(
+                    managed_token_id_wrapped!(STABLECOIN_TOKEN),
+                    0,
+                    10,
+                    OptionalValue::None,
+                )
+                .iter()
+                .map(|stats| stats.id)
+                .collect();
+
+            assert_eq!(by_output_token, vec![4]);
+            assert_eq!(by_egld, vec![1, 2]);
+            assert_eq!(by_stablecoin, vec![4]);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn get_auctions_by_status_works() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_auctions(&mut setup);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let live: Vec<u64> = sc
+                .get_auctions_by_status(SaleStatus::Live, 0, 10)
+                .iter()
+                .map(|stats| stats.id)
+                .collect();
+            let sold_out: Vec<u64> = sc
+                .get_auctions_by_status(SaleStatus::SoldOut, 0, 10)
+                .iter()
+                .map(|stats| stats.id)
+                .collect();
+            let hidden: Vec<u64> = sc
+                .get_auctions_by_status(SaleStatus::Hidden, 0, 10)
+                .iter()
+                .map(|stats| stats.id)
+                .collect();
+
+            assert_eq!(live, vec![1, 4]);
+            assert_eq!(sold_out, vec![2]);
+            assert_eq!(hidden, vec![3]);
+        })
+        .assert_ok();
+}
@@ -0,0 +1,425 @@
+use apc_sales::{
+    roles::{Role, RolesModule},
+    EmptyContract, ERR_MISSING_ROLE, ERR_NOT_AUCTION_SELLER, ERR_NOT_ENOUGHT_ITEMS,
+    ERR_PLATFORM_FEE_TOO_HIGH, MAX_PLATFORM_FEE_BPS, STARTING_AUCTION_ID,
+};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{Address, BoxedBytes, EgldOrEsdtTokenIdentifier, MultiValueEncoded};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+const PRICE: u64 = 1_000;
+const QUANTITY: u64 = 10;
+
+/** Lists the default token from an address that has no role */
+fn create_auction_as_seller<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    seller: &Address,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup
+        .create_auction_with(
+            seller,
+            helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+            helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+            QUANTITY,
+            |sc| {
+                let _ = sc.create_auction(
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    0,
+                    managed_biguint!(PRICE),
+                    0,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+}
+
+fn set_platform_fee<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    payee: &Address,
+    bps: u64,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.set_platform_fee(managed_address!(payee), bps),
+        )
+        .assert_ok();
+}
+
+#[test]
+fn anybody_can_sell_and_withdraw_their_proceeds() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let seller = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    create_auction_as_seller(&mut setup, &seller);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * 2);
+
+    // the owner has no proceeds to withdraw from the auctions of other sellers
+    setup.withdraw_balance();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(&seller, &setup.contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.withdraw_balance();
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&setup.owner_address, &rust_biguint!(0));
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&seller, &rust_biguint!(PRICE * 2));
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let auction = sc.auctions(STARTING_AUCTION_ID).get();
+            let seller_auctions = sc.get_auctions_by_seller(managed_address!(&seller), 0, 10);
+
+            assert_eq!(auction.seller, managed_address!(&seller));
+            assert_eq!(seller_auctions.len(), 1);
+            assert_eq!(seller_auctions.get(0).id, STARTING_AUCTION_ID);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn only_the_seller_can_retire_their_items() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let seller = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    create_auction_as_seller(&mut setup, &seller);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.retire_token_from_auction(STARTING_AUCTION_ID, &managed_biguint!(QUANTITY));
+            },
+        )
+        .assert_user_error(ERR_NOT_AUCTION_SELLER);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(&seller, &setup.contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.retire_token_from_auction(STARTING_AUCTION_ID, &managed_biguint!(QUANTITY));
+        })
+        .assert_ok();
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &seller,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(QUANTITY),
+        Option::Some(&BoxedBytes::empty()),
+    );
+}
+
+#[test]
+fn sellers_of_the_same_token_do_not_share_their_stock() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let first_seller = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+    let second_seller = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    create_auction_as_seller(&mut setup, &first_seller);
+    create_auction_as_seller(&mut setup, &second_seller);
+
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(PRICE * (QUANTITY + 1)));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(PRICE * (QUANTITY + 1)),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_NOT_ENOUGHT_ITEMS);
+}
+
+#[test]
+fn platform_fee_is_taken_before_the_seller_proceeds() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let seller = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+    let platform_address = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    set_platform_fee(&mut setup, &platform_address, 500);
+    create_auction_as_seller(&mut setup, &seller);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * 2);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let platform_fees: Vec<_> = sc
+                .get_accrued_fees(managed_address!(&platform_address))
+                .into_iter()
+                .map(|accrued_fee| accrued_fee.into_tuple())
+                .collect();
+            let earnings: Vec<_> = sc
+                .get_seller_earnings(managed_address!(&seller))
+                .into_iter()
+                .map(|earning| earning.into_tuple())
+                .collect();
+
+            assert_eq!(sc.get_pending_proceeds(STARTING_AUCTION_ID), 1_900u64);
+            assert_eq!(platform_fees.len(), 1);
+            assert_eq!(platform_fees[0].2, 100u64);
+            assert_eq!(earnings.len(), 1);
+            assert_eq!(earnings[0].0, EgldOrEsdtTokenIdentifier::egld());
+            assert_eq!(earnings[0].1, 0);
+            assert_eq!(earnings[0].2, 1_900u64);
+        })
+        .assert_ok();
+
+    // the earnings are kept once the proceeds are withdrawn
+    setup
+        .blockchain_wrapper
+        .execute_tx(&seller, &setup.contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.withdraw_balance();
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let earnings: Vec<_> = sc
+                .get_seller_earnings(managed_address!(&seller))
+                .into_iter()
+                .map(|earning| earning.into_tuple())
+                .collect();
+
+            assert_eq!(earnings[0].2, 1_900u64);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&seller, &rust_biguint!(1_900));
+}
+
+#[test]
+fn set_platform_fee_fails_if_share_is_too_high() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let owner_address = setup.owner_address.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.set_platform_fee(managed_address!(&owner_address), MAX_PLATFORM_FEE_BPS + 1),
+        )
+        .assert_user_error(ERR_PLATFORM_FEE_TOO_HIGH);
+}
+
+#[test]
+fn set_platform_fee_to_zero_removes_it() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let platform_address = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    set_platform_fee(&mut setup, &platform_address, 500);
+    set_platform_fee(&mut setup, &platform_address, 0);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc.platform_fee().is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn only_the_seller_can_set_the_fee_splits_of_their_auction() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let seller = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+    let owner_address = setup.owner_address.clone();
+
+    create_auction_as_seller(&mut setup, &seller);
+
+    // the treasurer can't route the proceeds of another seller
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut fee_splits = MultiValueEncoded::new();
+                fee_splits.push((managed_address!(&owner_address), 5_000u64).into());
+
+                sc.set_auction_fee_splits(STARTING_AUCTION_ID, fee_splits);
+            },
+        )
+        .assert_user_error(ERR_NOT_AUCTION_SELLER);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(&seller, &setup.contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut fee_splits = MultiValueEncoded::new();
+            fee_splits.push((managed_address!(&owner_address), 1_000u64).into());
+
+            sc.set_auction_fee_splits(STARTING_AUCTION_ID, fee_splits);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_fee_splits(STARTING_AUCTION_ID).len(), 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn default_fee_splits_only_apply_to_the_auctions_of_the_marketplace() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let seller = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+    let owner_address = setup.owner_address.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut fee_splits = MultiValueEncoded::new();
+                fee_splits.push((managed_address!(&owner_address), 5_000u64).into());
+
+                sc.set_default_fee_splits(fee_splits);
+            },
+        )
+        .assert_ok();
+
+    create_auction_as_seller(&mut setup, &seller);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc.get_fee_splits(STARTING_AUCTION_ID).is_empty());
+            assert_eq!(sc.get_pending_proceeds(STARTING_AUCTION_ID), PRICE);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn listing_managers_cannot_manage_the_auctions_of_other_sellers() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let seller = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+    let user_address = setup.user_address.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.grant_role(Role::ListingManager, managed_address!(&user_address)),
+        )
+        .assert_ok();
+
+    create_auction_as_seller(&mut setup, &seller);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.hide_auction(STARTING_AUCTION_ID),
+        )
+        .assert_user_error(ERR_NOT_AUCTION_SELLER);
+}
+
+#[test]
+fn role_holders_list_for_the_marketplace() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let user_address = setup.user_address.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.grant_role(Role::ListingManager, managed_address!(&user_address)),
+        )
+        .assert_ok();
+
+    create_auction_as_seller(&mut setup, &user_address);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+
+    // the proceeds of the marketplace are reserved to the treasurers
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.withdraw_balance(),
+        )
+        .assert_user_error(ERR_MISSING_ROLE);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let auction = sc.auctions(STARTING_AUCTION_ID).get();
+
+            assert_eq!(auction.seller, managed_address!(&owner_address));
+            assert!(sc
+                .auctions_by_seller(&managed_address!(&owner_address))
+                .contains(&STARTING_AUCTION_ID));
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID),
+                managed_biguint!(PRICE)
+            );
+        })
+        .assert_ok();
+}
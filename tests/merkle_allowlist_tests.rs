@@ -0,0 +1,314 @@
+use apc_sales::{
+    auction::AllowlistProof,
+    merkle::{self, Hash, MerkleTree},
+    EmptyContract, ERR_INVALID_ALLOWLIST_PROOF, ERR_MAX_PER_WALLET_EXCEEDED, ERR_NOT_ALLOWLISTED,
+    STARTING_AUCTION_ID,
+};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{Address, ManagedByteArray, ManagedVec};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+const PRICE: u64 = 100;
+const PRESALE_PRICE: u64 = 60;
+const START_TIMESTAMP: u64 = 10;
+const PUBLIC_START_TIMESTAMP: u64 = 20;
+const QUANTITY: u64 = 10;
+const PRESALE_MAX_PER_WALLET: u64 = 5;
+
+fn to_allowlist_proof(allowance: u64, proof: &[Hash]) -> AllowlistProof<DebugApi> {
+    let mut managed_proof = ManagedVec::new();
+
+    for hash in proof {
+        managed_proof.push(ManagedByteArray::new_from_bytes(hash));
+    }
+
+    return AllowlistProof {
+        allowance,
+        proof: managed_proof,
+    };
+}
+
+/** Returns the tree of `allowlist`, whose root is set on a running presale */
+fn setup_merkle_presale<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    allowlist: &[(Address, u64)],
+) -> MerkleTree
+where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    let tree = MerkleTree::from_allowlist(allowlist);
+    let root = tree.root();
+
+    setup.create_default_auction_buyable_in_egld(PRICE, START_TIMESTAMP, QUANTITY);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_presale(
+                    STARTING_AUCTION_ID,
+                    PUBLIC_START_TIMESTAMP,
+                    managed_biguint!(PRESALE_PRICE),
+                    managed_biguint!(PRESALE_MAX_PER_WALLET),
+                );
+
+                sc.set_allowlist_merkle_root(
+                    STARTING_AUCTION_ID,
+                    ManagedByteArray::new_from_bytes(&root),
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(START_TIMESTAMP);
+
+    return tree;
+}
+
+fn buy_with_proof<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    payment: u64,
+    allowance: u64,
+    proof: &[Hash],
+    expected_error: Option<&str>,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(payment));
+
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.user_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(payment),
+        |sc| {
+            sc.buy(
+                STARTING_AUCTION_ID,
+                OptionalValue::None,
+                OptionalValue::Some(to_allowlist_proof(allowance, proof)),
+            );
+        },
+    );
+
+    match expected_error {
+        Some(message) => tx_result.assert_user_error(message),
+        None => tx_result.assert_ok(),
+    }
+}
+
+#[test]
+fn merkle_tree_proofs_are_valid() {
+    let leaves: Vec<Hash> = (0u8..5).map(|i| merkle::keccak256(&[i])).collect();
+    let tree = MerkleTree::new(leaves.clone());
+
+    for (index, leaf) in leaves.iter().enumerate() {
+        assert!(merkle::verify(&tree.root(), leaf, &tree.proof(index)));
+        assert!(!merkle::verify(
+            &tree.root(),
+            leaf,
+            &tree.proof((index + 1) % leaves.len())
+        ));
+    }
+}
+
+#[test]
+fn merkle_tree_of_one_leaf() {
+    let leaf = merkle::keccak256(b"leaf");
+    let tree = MerkleTree::new(vec![leaf]);
+
+    assert_eq!(tree.root(), leaf);
+    assert!(tree.proof(0).is_empty());
+}
+
+#[test]
+fn presale_sells_with_valid_proof() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let other_address = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+    let allowlist = [
+        (other_address, 0),
+        (setup.user_address.clone(), 0),
+        (setup.owner_address.clone(), 0),
+    ];
+
+    let tree = setup_merkle_presale(&mut setup, &allowlist);
+
+    buy_with_proof(&mut setup, PRESALE_PRICE * 2, 0, &tree.proof(1), None);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID),
+                PRESALE_PRICE * 2
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn presale_refuses_proof_of_another_address() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let other_address = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+    let allowlist = [(other_address, 0), (setup.owner_address.clone(), 0)];
+
+    let tree = setup_merkle_presale(&mut setup, &allowlist);
+
+    buy_with_proof(
+        &mut setup,
+        PRESALE_PRICE,
+        0,
+        &tree.proof(0),
+        Some(ERR_INVALID_ALLOWLIST_PROOF),
+    );
+}
+
+#[test]
+fn presale_refuses_forged_allowance() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let allowlist = [
+        (setup.user_address.clone(), 1),
+        (setup.owner_address.clone(), 0),
+    ];
+
+    let tree = setup_merkle_presale(&mut setup, &allowlist);
+
+    buy_with_proof(
+        &mut setup,
+        PRESALE_PRICE,
+        3,
+        &tree.proof(0),
+        Some(ERR_INVALID_ALLOWLIST_PROOF),
+    );
+}
+
+#[test]
+fn presale_enforces_allowance_of_proof() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let allowlist = [
+        (setup.user_address.clone(), 1),
+        (setup.owner_address.clone(), 0),
+    ];
+
+    let tree = setup_merkle_presale(&mut setup, &allowlist);
+
+    buy_with_proof(
+        &mut setup,
+        PRESALE_PRICE * 2,
+        1,
+        &tree.proof(0),
+        Some(ERR_MAX_PER_WALLET_EXCEEDED),
+    );
+
+    buy_with_proof(&mut setup, PRESALE_PRICE, 1, &tree.proof(0), None);
+}
+
+#[test]
+fn presale_without_proof_requires_allowlist() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let allowlist = [(setup.user_address.clone(), 0)];
+
+    setup_merkle_presale(&mut setup, &allowlist);
+
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(PRESALE_PRICE));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(PRESALE_PRICE),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_NOT_ALLOWLISTED);
+}
+
+#[test]
+fn proof_is_refused_once_root_is_cleared() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let allowlist = [
+        (setup.user_address.clone(), 0),
+        (setup.owner_address.clone(), 0),
+    ];
+
+    let tree = setup_merkle_presale(&mut setup, &allowlist);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.clear_allowlist_merkle_root(STARTING_AUCTION_ID);
+            },
+        )
+        .assert_ok();
+
+    buy_with_proof(
+        &mut setup,
+        PRESALE_PRICE,
+        0,
+        &tree.proof(0),
+        Some(ERR_INVALID_ALLOWLIST_PROOF),
+    );
+}
+
+#[test]
+fn is_eligible_checks_proof() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let user_address = setup.user_address.clone();
+    let owner_address = setup.owner_address.clone();
+    let allowlist = [(user_address.clone(), 2), (owner_address.clone(), 0)];
+
+    let tree = setup_merkle_presale(&mut setup, &allowlist);
+    let root = tree.root();
+    let user_proof = tree.proof(0);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc.is_eligible(
+                STARTING_AUCTION_ID,
+                managed_address!(&user_address),
+                OptionalValue::Some(to_allowlist_proof(2, &user_proof)),
+            ));
+            assert!(!sc.is_eligible(
+                STARTING_AUCTION_ID,
+                managed_address!(&owner_address),
+                OptionalValue::Some(to_allowlist_proof(2, &user_proof)),
+            ));
+            assert!(!sc.is_eligible(
+                STARTING_AUCTION_ID,
+                managed_address!(&user_address),
+                OptionalValue::None,
+            ));
+
+            match sc.get_allowlist_merkle_root(STARTING_AUCTION_ID) {
+                OptionalValue::Some(merkle_root) => {
+                    assert_eq!(merkle_root.to_byte_array(), root)
+                }
+                OptionalValue::None => panic!("The Merkle root should be set"),
+            }
+        })
+        .assert_ok();
+}
@@ -0,0 +1,380 @@
+use apc_sales::{
+    auction::{Auction, AuctionStatus, LegacyAuction},
+    EmptyContract, STARTING_AUCTION_ID, STORAGE_VERSION,
+};
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, TokenIdentifier};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+#[test]
+fn upgrade_migrates_legacy_auctions() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 10;
+    const START_TIMESTAMP: u64 = 5;
+    const MAX_QUANTITY: u64 = 100;
+    const CURRENT_QUANTITY: u64 = 60;
+
+    // simulate the storage of a contract deployed before the versioning
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.legacy_auctions(STARTING_AUCTION_ID).set(LegacyAuction {
+                    input_token_id: EgldOrEsdtTokenIdentifier::egld(),
+                    input_token_nonce: 0,
+                    output_token_id: TokenIdentifier::<DebugApi>::from_esdt_bytes(
+                        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+                    ),
+                    output_token_nonce: helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+                    price: managed_biguint!(PRICE),
+                    start_timestamp: START_TIMESTAMP,
+                    current_quantity: managed_biguint!(CURRENT_QUANTITY),
+                    max_quantity: managed_biguint!(MAX_QUANTITY),
+                });
+                sc.next_auction_id().set(STARTING_AUCTION_ID + 1);
+                sc.storage_version().clear();
+            },
+        )
+        .assert_ok();
+
+    // simulate upgrade
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init();
+            },
+        )
+        .assert_ok();
+
+    let owner_address = setup.owner_address.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.storage_version().get(), STORAGE_VERSION);
+            assert_eq!(sc.next_auction_id().get(), STARTING_AUCTION_ID + 1);
+
+            assert_eq!(
+                sc.auctions(STARTING_AUCTION_ID).get(),
+                Auction {
+                    input_token_id: EgldOrEsdtTokenIdentifier::egld(),
+                    input_token_nonce: 0,
+                    output_token_id: TokenIdentifier::<DebugApi>::from_esdt_bytes(
+                        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+                    ),
+                    output_token_nonce: helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+                    price: managed_biguint!(PRICE),
+                    start_timestamp: START_TIMESTAMP,
+                    end_timestamp: 0,
+                    current_quantity: managed_biguint!(CURRENT_QUANTITY),
+                    max_quantity: managed_biguint!(MAX_QUANTITY),
+                    max_per_wallet: managed_biguint!(0),
+                    status: AuctionStatus::Active,
+                    seller: managed_address!(&owner_address),
+                }
+            );
+
+            // the listing indexes are built from the migrated auctions
+            assert!(sc.active_auctions().contains(&STARTING_AUCTION_ID));
+            assert!(sc
+                .auctions_by_seller(&managed_address!(&owner_address))
+                .contains(&STARTING_AUCTION_ID));
+            assert!(sc
+                .auctions_by_output_token(&TokenIdentifier::from_esdt_bytes(
+                    helpers::DEFAULT_AUCTION_OUTPUT_TOKEN
+                ))
+                .contains(&STARTING_AUCTION_ID));
+            assert!(sc
+                .auctions_by_payment_token(&EgldOrEsdtTokenIdentifier::egld())
+                .contains(&STARTING_AUCTION_ID));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn upgrade_credits_the_balance_left_by_the_legacy_sales() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let contract_address = setup.contract_wrapper.address_ref().clone();
+
+    const PRICE: u64 = 10;
+
+    // simulate 40 and 5 items sold by a legacy contract, part of the first sales already withdrawn
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                for (offset, sold) in [(0u64, 40u64), (1, 5)] {
+                    sc.legacy_auctions(STARTING_AUCTION_ID + offset)
+                        .set(LegacyAuction {
+                            input_token_id: EgldOrEsdtTokenIdentifier::egld(),
+                            input_token_nonce: 0,
+                            output_token_id: TokenIdentifier::<DebugApi>::from_esdt_bytes(
+                                helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+                            ),
+                            output_token_nonce: helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+                            price: managed_biguint!(PRICE),
+                            start_timestamp: 0,
+                            current_quantity: managed_biguint!(100 - sold),
+                            max_quantity: managed_biguint!(100),
+                        });
+                }
+                sc.next_auction_id().set(STARTING_AUCTION_ID + 2);
+                sc.storage_version().clear();
+            },
+        )
+        .assert_ok();
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&contract_address, &rust_biguint!(420));
+
+    // simulate upgrade
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init();
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID),
+                managed_biguint!(400)
+            );
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID + 1),
+                managed_biguint!(20)
+            );
+        })
+        .assert_ok();
+
+    setup.withdraw_balance();
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&setup.owner_address, &rust_biguint!(420));
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&contract_address, &rust_biguint!(0));
+
+    // a later upgrade doesn't credit them again
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init();
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID),
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn upgrade_credits_nothing_to_the_auctions_hidden_by_the_legacy_contract() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let contract_address = setup.contract_wrapper.address_ref().clone();
+
+    const PRICE: u64 = 10;
+
+    // the first auction was hidden by setting its max quantity to 0, 5 items of the second were sold
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                for (offset, current_quantity, max_quantity) in [(0u64, 99u64, 0u64), (1, 95, 100)]
+                {
+                    sc.legacy_auctions(STARTING_AUCTION_ID + offset)
+                        .set(LegacyAuction {
+                            input_token_id: EgldOrEsdtTokenIdentifier::egld(),
+                            input_token_nonce: 0,
+                            output_token_id: TokenIdentifier::<DebugApi>::from_esdt_bytes(
+                                helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+                            ),
+                            output_token_nonce: helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+                            price: managed_biguint!(PRICE),
+                            start_timestamp: 0,
+                            current_quantity: managed_biguint!(current_quantity),
+                            max_quantity: managed_biguint!(max_quantity),
+                        });
+                }
+                sc.next_auction_id().set(STARTING_AUCTION_ID + 2);
+                sc.storage_version().clear();
+            },
+        )
+        .assert_ok();
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&contract_address, &rust_biguint!(50));
+
+    // simulate upgrade
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init();
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID),
+                managed_biguint!(0)
+            );
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID + 1),
+                managed_biguint!(50)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn upgrade_keeps_migrated_auctions() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 10;
+    const START_TIMESTAMP: u64 = 5;
+    const END_TIMESTAMP: u64 = 50;
+    const QUANTITY: u64 = 3;
+
+    setup.create_default_timed_auction_buyable_in_egld(
+        PRICE,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+
+    // simulate upgrade
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init();
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let auction = sc.auctions(STARTING_AUCTION_ID).get();
+
+            assert_eq!(auction.end_timestamp, END_TIMESTAMP);
+            assert_eq!(auction.current_quantity, QUANTITY);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn upgrade_sets_the_status_of_auctions() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 10;
+    const QUANTITY: u64 = 3;
+
+    // simulate the storage of a contract deployed before the versioning,
+    // the second auction was hidden by setting its max quantity to 0, its current quantity is stale
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                for (offset, (current_quantity, max_quantity)) in
+                    [(QUANTITY, QUANTITY), (99, 0)].iter().enumerate()
+                {
+                    sc.legacy_auctions(STARTING_AUCTION_ID + offset as u64)
+                        .set(LegacyAuction {
+                            input_token_id: EgldOrEsdtTokenIdentifier::egld(),
+                            input_token_nonce: 0,
+                            output_token_id: TokenIdentifier::<DebugApi>::from_esdt_bytes(
+                                helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+                            ),
+                            output_token_nonce: helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+                            price: managed_biguint!(PRICE),
+                            start_timestamp: 0,
+                            current_quantity: managed_biguint!(*current_quantity),
+                            max_quantity: managed_biguint!(*max_quantity),
+                        });
+                }
+                sc.next_auction_id().set(STARTING_AUCTION_ID + 2);
+                sc.storage_version().clear();
+            },
+        )
+        .assert_ok();
+
+    // simulate upgrade
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init();
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.storage_version().get(), STORAGE_VERSION);
+            assert_eq!(
+                sc.auctions(STARTING_AUCTION_ID).get().status,
+                AuctionStatus::Active
+            );
+            assert_eq!(
+                sc.auctions(STARTING_AUCTION_ID + 1).get().status,
+                AuctionStatus::Hidden
+            );
+            assert_eq!(
+                sc.auctions(STARTING_AUCTION_ID + 1).get().current_quantity,
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+}
@@ -1,6 +1,28 @@
 mod admin_endpoints_tests;
+mod bonding_curve_tests;
+mod bundle_auction_tests;
+mod buy_cart_tests;
+mod buy_many_tests;
 mod buy_tests;
 mod create_auction_tests;
+mod dutch_auction_tests;
+mod english_auction_tests;
+mod events_tests;
+mod fee_splits_tests;
 mod helpers;
-mod only_owner_endpoints_tests;
+mod listing_views_tests;
+mod marketplace_tests;
+mod merkle_allowlist_tests;
+mod migration_tests;
+mod mystery_box_tests;
+mod pause_tests;
+mod payment_tokens_tests;
+mod presale_tests;
+mod price_schedule_tests;
+mod restricted_endpoints_tests;
+mod roles_tests;
+mod royalties_tests;
+mod scenario_go_tests;
+mod update_auction_tests;
 mod views_tests;
+mod wasm_endpoints_tests;
@@ -0,0 +1,341 @@
+use apc_sales::{
+    auction::AuctionKind, EmptyContract, ERR_MYSTERY_BOX_BAD_ITEM, ERR_NOT_ENOUGHT_ITEMS,
+    ERR_WRONG_AUCTION_KIND, STARTING_AUCTION_ID,
+};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{Address, BoxedBytes, EgldOrEsdtTokenIdentifier};
+use multiversx_sc_scenario::{
+    managed_biguint, rust_biguint, testing_framework::TxTokenTransfer, DebugApi,
+};
+
+use crate::helpers;
+
+const PRICE: u64 = 100;
+const COLLECTION: &[u8] = b"LOOT-aaaaaa";
+const OTHER_COLLECTION: &[u8] = b"OTHER-bbbbbb";
+
+/** One NFT per nonce, from `first_nonce` to `last_nonce` included */
+fn deposit_nfts<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    collection: &[u8],
+    first_nonce: u64,
+    last_nonce: u64,
+) -> Vec<TxTokenTransfer>
+where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    let mut deposit = Vec::new();
+
+    for nonce in first_nonce..=last_nonce {
+        setup.blockchain_wrapper.set_nft_balance(
+            &setup.owner_address,
+            collection,
+            nonce,
+            &rust_biguint!(1),
+            &BoxedBytes::empty(),
+        );
+
+        deposit.push(TxTokenTransfer {
+            token_identifier: collection.to_vec(),
+            nonce,
+            value: rust_biguint!(1),
+        });
+    }
+
+    return deposit;
+}
+
+fn create_mystery_box<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    items: u64,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    let deposit = deposit_nfts(setup, COLLECTION, 1, items);
+
+    setup
+        .blockchain_wrapper
+        .execute_esdt_multi_transfer(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &deposit,
+            |sc| {
+                let _ = sc.create_mystery_box_auction(
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    0,
+                    managed_biguint!(PRICE),
+                    0,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+}
+
+fn count_owned_nfts<ContractObjBuilder>(
+    setup: &helpers::ContractSetup<ContractObjBuilder>,
+    address: &Address,
+    items: u64,
+) -> u64
+where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    return (1..=items)
+        .filter(|nonce| {
+            setup
+                .blockchain_wrapper
+                .get_esdt_balance(address, COLLECTION, *nonce)
+                == rust_biguint!(1)
+        })
+        .count() as u64;
+}
+
+#[test]
+fn create_mystery_box_fills_pool() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_mystery_box(&mut setup, 5);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let stats = sc.get_auction_stats(STARTING_AUCTION_ID);
+
+            assert_eq!(stats.kind, AuctionKind::MysteryBox);
+            assert_eq!(stats.auction.output_token_nonce, 0);
+            assert_eq!(stats.auction.current_quantity, managed_biguint!(5));
+            assert_eq!(sc.get_mystery_box_pool_size(STARTING_AUCTION_ID), 5);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_draws_items_without_replacement() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let user_address = setup.user_address.clone();
+    let contract_address = setup.contract_wrapper.address_ref().clone();
+
+    create_mystery_box(&mut setup, 5);
+
+    setup
+        .blockchain_wrapper
+        .set_block_random_seed(Box::new([7u8; 48]));
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * 2);
+
+    assert_eq!(count_owned_nfts(&setup, &user_address, 5), 2);
+    assert_eq!(count_owned_nfts(&setup, &contract_address, 5), 3);
+
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * 3);
+
+    assert_eq!(count_owned_nfts(&setup, &user_address, 5), 5);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_mystery_box_pool_size(STARTING_AUCTION_ID), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_fails_if_pool_is_too_small() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_mystery_box(&mut setup, 2);
+
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(PRICE * 3));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(PRICE * 3),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_NOT_ENOUGHT_ITEMS);
+}
+
+#[test]
+fn add_token_to_mystery_box_grows_pool() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_mystery_box(&mut setup, 2);
+
+    let deposit = deposit_nfts(&mut setup, COLLECTION, 3, 6);
+
+    setup
+        .blockchain_wrapper
+        .execute_esdt_multi_transfer(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &deposit,
+            |sc| {
+                sc.add_token_to_auction(STARTING_AUCTION_ID);
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_mystery_box_pool_size(STARTING_AUCTION_ID), 6);
+            assert_eq!(
+                sc.get_auction_stats(STARTING_AUCTION_ID)
+                    .auction
+                    .current_quantity,
+                managed_biguint!(6)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn add_token_to_mystery_box_merges_nonces_deposited_again() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_mystery_box(&mut setup, 2);
+
+    let deposit = deposit_nfts(&mut setup, COLLECTION, 2, 3);
+
+    setup
+        .blockchain_wrapper
+        .execute_esdt_multi_transfer(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &deposit,
+            |sc| {
+                sc.add_token_to_auction(STARTING_AUCTION_ID);
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let pool: Vec<(u64, u64)> = sc.mystery_box_pool(STARTING_AUCTION_ID).iter().collect();
+
+            assert_eq!(pool, vec![(1, 1), (2, 2), (3, 1)]);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn add_token_to_mystery_box_fails_with_other_collection() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_mystery_box(&mut setup, 2);
+
+    let deposit = deposit_nfts(&mut setup, OTHER_COLLECTION, 1, 1);
+
+    setup
+        .blockchain_wrapper
+        .execute_esdt_multi_transfer(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &deposit,
+            |sc| {
+                sc.add_token_to_auction(STARTING_AUCTION_ID);
+            },
+        )
+        .assert_user_error(ERR_MYSTERY_BOX_BAD_ITEM);
+}
+
+#[test]
+fn mystery_box_pool_keeps_one_entry_per_deposited_nonce() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let user_address = setup.user_address.clone();
+    let mut deposit = Vec::new();
+
+    for (nonce, amount) in [(1u64, 1_000u64), (2, 1)] {
+        setup.blockchain_wrapper.set_nft_balance(
+            &setup.owner_address,
+            COLLECTION,
+            nonce,
+            &rust_biguint!(amount),
+            &BoxedBytes::empty(),
+        );
+
+        deposit.push(TxTokenTransfer {
+            token_identifier: COLLECTION.to_vec(),
+            nonce,
+            value: rust_biguint!(amount),
+        });
+    }
+
+    setup
+        .blockchain_wrapper
+        .execute_esdt_multi_transfer(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &deposit,
+            |sc| {
+                let _ = sc.create_mystery_box_auction(
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    0,
+                    managed_biguint!(PRICE),
+                    0,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.mystery_box_pool(STARTING_AUCTION_ID).len(), 2);
+            assert_eq!(sc.get_mystery_box_pool_size(STARTING_AUCTION_ID), 1_001);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .set_block_random_seed(Box::new([7u8; 48]));
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * 3);
+
+    let bought = setup
+        .blockchain_wrapper
+        .get_esdt_balance(&user_address, COLLECTION, 1)
+        + setup
+            .blockchain_wrapper
+            .get_esdt_balance(&user_address, COLLECTION, 2);
+
+    assert_eq!(bought, rust_biguint!(3));
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_mystery_box_pool_size(STARTING_AUCTION_ID), 998);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn set_honor_royalties_fails_for_mystery_box() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    create_mystery_box(&mut setup, 2);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_honor_royalties(STARTING_AUCTION_ID, true);
+            },
+        )
+        .assert_user_error(ERR_WRONG_AUCTION_KIND);
+}
@@ -0,0 +1,207 @@
+use apc_sales::{
+    auction::SaleStatus, EmptyContract, ERR_AUCTION_NOT_ACTIVE, ERR_AUCTION_NOT_PAUSED,
+    ERR_AUCTION_PAUSED, ERR_MARKETPLACE_PAUSED, STARTING_AUCTION_ID,
+};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::BoxedBytes;
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+const PRICE: u64 = 10;
+const QUANTITY: u64 = 5;
+
+fn call_as_owner<ContractObjBuilder, TxFn>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    tx_fn: TxFn,
+    expected_error: Option<&str>,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+    TxFn: FnOnce(apc_sales::ContractObj<DebugApi>),
+{
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(0),
+        tx_fn,
+    );
+
+    match expected_error {
+        Some(message) => tx_result.assert_user_error(message),
+        None => tx_result.assert_ok(),
+    }
+}
+
+fn try_buy<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    expected_error: Option<&str>,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(PRICE));
+
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.user_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(PRICE),
+        |sc| {
+            sc.buy(
+                STARTING_AUCTION_ID,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        },
+    );
+
+    match expected_error {
+        Some(message) => tx_result.assert_user_error(message),
+        None => tx_result.assert_ok(),
+    }
+}
+
+#[test]
+fn buy_fails_while_the_marketplace_is_paused() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+
+    call_as_owner(&mut setup, |sc| sc.pause(), None);
+    try_buy(&mut setup, Some(ERR_MARKETPLACE_PAUSED));
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc.paused().get());
+        })
+        .assert_ok();
+
+    call_as_owner(&mut setup, |sc| sc.unpause(), None);
+    try_buy(&mut setup, None);
+}
+
+#[test]
+fn buy_fails_while_the_auction_is_paused() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+
+    call_as_owner(&mut setup, |sc| sc.pause_auction(STARTING_AUCTION_ID), None);
+    call_as_owner(
+        &mut setup,
+        |sc| sc.pause_auction(STARTING_AUCTION_ID),
+        Some(ERR_AUCTION_NOT_ACTIVE),
+    );
+    try_buy(&mut setup, Some(ERR_AUCTION_PAUSED));
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc.is_auction_paused(STARTING_AUCTION_ID));
+            assert_eq!(
+                sc.get_auction_stats(STARTING_AUCTION_ID).status,
+                SaleStatus::Paused
+            );
+        })
+        .assert_ok();
+
+    call_as_owner(
+        &mut setup,
+        |sc| sc.unpause_auction(STARTING_AUCTION_ID),
+        None,
+    );
+    call_as_owner(
+        &mut setup,
+        |sc| sc.unpause_auction(STARTING_AUCTION_ID),
+        Some(ERR_AUCTION_NOT_PAUSED),
+    );
+    try_buy(&mut setup, None);
+}
+
+#[test]
+fn is_eligible_is_false_while_paused() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+
+    call_as_owner(&mut setup, |sc| sc.pause(), None);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(!sc.is_eligible(
+                STARTING_AUCTION_ID,
+                managed_address!(&user_address),
+                OptionalValue::None
+            ));
+        })
+        .assert_ok();
+
+    call_as_owner(&mut setup, |sc| sc.unpause(), None);
+    call_as_owner(&mut setup, |sc| sc.pause_auction(STARTING_AUCTION_ID), None);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(!sc.is_eligible(
+                STARTING_AUCTION_ID,
+                managed_address!(&user_address),
+                OptionalValue::None
+            ));
+        })
+        .assert_ok();
+
+    call_as_owner(
+        &mut setup,
+        |sc| sc.unpause_auction(STARTING_AUCTION_ID),
+        None,
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc.is_eligible(
+                STARTING_AUCTION_ID,
+                managed_address!(&user_address),
+                OptionalValue::None
+            ));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn admin_endpoints_work_while_paused() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+
+    call_as_owner(&mut setup, |sc| sc.pause(), None);
+    call_as_owner(&mut setup, |sc| sc.pause_auction(STARTING_AUCTION_ID), None);
+
+    setup.retire_auction(STARTING_AUCTION_ID, QUANTITY - 1);
+    setup.withdraw_balance();
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.owner_address,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(QUANTITY - 1),
+        Option::Some(&BoxedBytes::empty()),
+    );
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&setup.owner_address, &rust_biguint!(PRICE));
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID),
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+}
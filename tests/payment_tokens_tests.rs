@@ -0,0 +1,242 @@
+use apc_sales::{
+    EmptyContract, ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH,
+    ERR_PAYMENT_TOKEN_IS_THE_AUCTION_ONE, ERR_WRONG_AUCTION_KIND, STARTING_AUCTION_ID,
+};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{BoxedBytes, EgldOrEsdtTokenIdentifier};
+use multiversx_sc_scenario::{managed_biguint, managed_token_id_wrapped, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+const EGLD_PRICE: u64 = 100;
+const STABLECOIN_TOKEN: &[u8] = b"USDC-aaaaaa";
+const STABLECOIN_PRICE: u64 = 5;
+const QUANTITY: u64 = 10;
+
+fn set_stablecoin_price<ContractObjBuilder>(setup: &mut helpers::ContractSetup<ContractObjBuilder>)
+where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_payment_token_price(
+                    STARTING_AUCTION_ID,
+                    managed_token_id_wrapped!(STABLECOIN_TOKEN),
+                    0,
+                    managed_biguint!(STABLECOIN_PRICE),
+                );
+            },
+        )
+        .assert_ok();
+}
+
+fn buy_in_stablecoin<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    payment: u64,
+    expected_error: Option<&str>,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup.blockchain_wrapper.set_esdt_balance(
+        &setup.user_address,
+        STABLECOIN_TOKEN,
+        &rust_biguint!(payment),
+    );
+
+    let tx_result = setup.blockchain_wrapper.execute_esdt_transfer(
+        &setup.user_address,
+        &setup.contract_wrapper,
+        STABLECOIN_TOKEN,
+        0,
+        &rust_biguint!(payment),
+        |sc| {
+            sc.buy(
+                STARTING_AUCTION_ID,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        },
+    );
+
+    match expected_error {
+        Some(message) => tx_result.assert_user_error(message),
+        None => tx_result.assert_ok(),
+    }
+}
+
+#[test]
+fn buy_with_extra_payment_token() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(EGLD_PRICE, 0, QUANTITY);
+    set_stablecoin_price(&mut setup);
+
+    buy_in_stablecoin(&mut setup, STABLECOIN_PRICE * 2, None);
+    setup.buy_in_egld(STARTING_AUCTION_ID, EGLD_PRICE);
+
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.user_address,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(3),
+        Option::Some(&BoxedBytes::empty()),
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let pending_proceeds: Vec<_> = sc
+                .get_pending_proceeds_per_token(STARTING_AUCTION_ID)
+                .into_iter()
+                .map(|pending| pending.into_tuple())
+                .collect();
+
+            assert_eq!(pending_proceeds.len(), 2);
+            assert_eq!(pending_proceeds[0].0, EgldOrEsdtTokenIdentifier::egld());
+            assert_eq!(pending_proceeds[0].2, EGLD_PRICE);
+            assert_eq!(
+                pending_proceeds[1].0,
+                managed_token_id_wrapped!(STABLECOIN_TOKEN)
+            );
+            assert_eq!(pending_proceeds[1].2, STABLECOIN_PRICE * 2);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn withdraw_balance_sweeps_every_payment_token() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(EGLD_PRICE, 0, QUANTITY);
+    set_stablecoin_price(&mut setup);
+
+    buy_in_stablecoin(&mut setup, STABLECOIN_PRICE * 3, None);
+    setup.buy_in_egld(STARTING_AUCTION_ID, EGLD_PRICE);
+
+    setup.withdraw_balance();
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&setup.owner_address, &rust_biguint!(EGLD_PRICE));
+    setup.blockchain_wrapper.check_esdt_balance(
+        &setup.owner_address,
+        STABLECOIN_TOKEN,
+        &rust_biguint!(STABLECOIN_PRICE * 3),
+    );
+
+    // nothing left to withdraw
+    setup.withdraw_balance();
+
+    setup.blockchain_wrapper.check_esdt_balance(
+        &setup.owner_address,
+        STABLECOIN_TOKEN,
+        &rust_biguint!(STABLECOIN_PRICE * 3),
+    );
+}
+
+#[test]
+fn get_payment_tokens_lists_every_price() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(EGLD_PRICE, 0, QUANTITY);
+    set_stablecoin_price(&mut setup);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let payment_tokens: Vec<_> = sc
+                .get_payment_tokens(STARTING_AUCTION_ID)
+                .into_iter()
+                .map(|payment_token| payment_token.into_tuple())
+                .collect();
+
+            assert_eq!(payment_tokens.len(), 2);
+            assert_eq!(payment_tokens[0].0, EgldOrEsdtTokenIdentifier::egld());
+            assert_eq!(payment_tokens[0].2, EGLD_PRICE);
+            assert_eq!(
+                payment_tokens[1].0,
+                managed_token_id_wrapped!(STABLECOIN_TOKEN)
+            );
+            assert_eq!(payment_tokens[1].1, 0);
+            assert_eq!(payment_tokens[1].2, STABLECOIN_PRICE);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_fails_once_payment_token_is_removed() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(EGLD_PRICE, 0, QUANTITY);
+    set_stablecoin_price(&mut setup);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_payment_token(
+                    STARTING_AUCTION_ID,
+                    managed_token_id_wrapped!(STABLECOIN_TOKEN),
+                    0,
+                );
+            },
+        )
+        .assert_ok();
+
+    buy_in_stablecoin(
+        &mut setup,
+        STABLECOIN_PRICE,
+        Some(ERR_INVALID_PAYMENT_TOKEN_IDENTIFIER_MISMATCH),
+    );
+}
+
+#[test]
+fn remove_payment_token_fails_for_input_token() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(EGLD_PRICE, 0, QUANTITY);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_payment_token(STARTING_AUCTION_ID, EgldOrEsdtTokenIdentifier::egld(), 0);
+            },
+        )
+        .assert_user_error(ERR_PAYMENT_TOKEN_IS_THE_AUCTION_ONE);
+}
+
+#[test]
+fn set_payment_token_price_fails_for_dutch_auction() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_dutch_auction_in_egld(100, 50, 0, 100, 0, QUANTITY);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_payment_token_price(
+                    STARTING_AUCTION_ID,
+                    managed_token_id_wrapped!(STABLECOIN_TOKEN),
+                    0,
+                    managed_biguint!(STABLECOIN_PRICE),
+                );
+            },
+        )
+        .assert_user_error(ERR_WRONG_AUCTION_KIND);
+}
@@ -0,0 +1,343 @@
+use apc_sales::{
+    EmptyContract, ERR_MAX_PER_WALLET_EXCEEDED, ERR_NOT_ALLOWLISTED,
+    ERR_PRESALE_BAD_PUBLIC_START_TIMESTAMP, STARTING_AUCTION_ID,
+};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{Address, MultiValueEncoded};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+const PUBLIC_PRICE: u64 = 100;
+const PRESALE_PRICE: u64 = 60;
+const START_TIMESTAMP: u64 = 10;
+const PUBLIC_START_TIMESTAMP: u64 = 20;
+const QUANTITY: u64 = 10;
+const PRESALE_MAX_PER_WALLET: u64 = 2;
+
+fn setup_presale<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    allowlist: &[Address],
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup.create_default_auction_buyable_in_egld(PUBLIC_PRICE, START_TIMESTAMP, QUANTITY);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_presale(
+                    STARTING_AUCTION_ID,
+                    PUBLIC_START_TIMESTAMP,
+                    managed_biguint!(PRESALE_PRICE),
+                    managed_biguint!(PRESALE_MAX_PER_WALLET),
+                );
+
+                let mut addresses = MultiValueEncoded::new();
+                for address in allowlist {
+                    addresses.push(managed_address!(address));
+                }
+
+                sc.add_to_allowlist(STARTING_AUCTION_ID, addresses);
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn presale_refuses_addresses_not_allowlisted() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let owner_address = setup.owner_address.clone();
+
+    setup_presale(&mut setup, &[owner_address]);
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(START_TIMESTAMP);
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(PRESALE_PRICE));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(PRESALE_PRICE),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_NOT_ALLOWLISTED);
+}
+
+#[test]
+fn presale_sells_at_presale_price_to_allowlist() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup_presale(&mut setup, &[user_address]);
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(START_TIMESTAMP);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRESALE_PRICE * PRESALE_MAX_PER_WALLET);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID),
+                PRESALE_PRICE * PRESALE_MAX_PER_WALLET
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn presale_enforces_its_own_max_per_wallet() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup_presale(&mut setup, &[user_address]);
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(START_TIMESTAMP);
+
+    const BUY_QUANTITY: u64 = PRESALE_MAX_PER_WALLET + 1;
+
+    setup.blockchain_wrapper.set_egld_balance(
+        &setup.user_address,
+        &rust_biguint!(PRESALE_PRICE * BUY_QUANTITY),
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(PRESALE_PRICE * BUY_QUANTITY),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_MAX_PER_WALLET_EXCEEDED);
+}
+
+#[test]
+fn quote_follows_the_phases() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup_presale(&mut setup, &[]);
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(START_TIMESTAMP);
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.quote(STARTING_AUCTION_ID, managed_biguint!(2)),
+                PRESALE_PRICE * 2
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(PUBLIC_START_TIMESTAMP);
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.quote(STARTING_AUCTION_ID, managed_biguint!(2)),
+                PUBLIC_PRICE * 2
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn public_sale_is_opened_to_everyone_at_public_price() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let owner_address = setup.owner_address.clone();
+
+    setup_presale(&mut setup, &[owner_address]);
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(PUBLIC_START_TIMESTAMP);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PUBLIC_PRICE * 3);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID),
+                PUBLIC_PRICE * 3
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn removed_address_cannot_buy_presale() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup_presale(&mut setup, &[user_address.clone()]);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut addresses = MultiValueEncoded::new();
+                addresses.push(managed_address!(&user_address));
+
+                sc.remove_from_allowlist(STARTING_AUCTION_ID, addresses);
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(START_TIMESTAMP);
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(PRESALE_PRICE));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(PRESALE_PRICE),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_NOT_ALLOWLISTED);
+}
+
+#[test]
+fn is_eligible_follows_the_phases() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let user_address = setup.user_address.clone();
+    let owner_address = setup.owner_address.clone();
+
+    setup_presale(&mut setup, &[user_address.clone()]);
+
+    let timestamps_and_eligibilities = [
+        (START_TIMESTAMP - 1, false, false),
+        (START_TIMESTAMP, true, false),
+        (PUBLIC_START_TIMESTAMP, true, true),
+    ];
+
+    for (timestamp, user_eligible, owner_eligible) in timestamps_and_eligibilities.iter() {
+        setup.blockchain_wrapper.set_block_timestamp(*timestamp);
+
+        setup
+            .blockchain_wrapper
+            .execute_query(&setup.contract_wrapper, |sc| {
+                assert_eq!(
+                    sc.is_eligible(
+                        STARTING_AUCTION_ID,
+                        managed_address!(&user_address),
+                        OptionalValue::None
+                    ),
+                    *user_eligible
+                );
+                assert_eq!(
+                    sc.is_eligible(
+                        STARTING_AUCTION_ID,
+                        managed_address!(&owner_address),
+                        OptionalValue::None
+                    ),
+                    *owner_eligible
+                );
+            })
+            .assert_ok();
+    }
+}
+
+#[test]
+fn set_presale_fails_if_public_start_before_start() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(PUBLIC_PRICE, START_TIMESTAMP, QUANTITY);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_presale(
+                    STARTING_AUCTION_ID,
+                    START_TIMESTAMP,
+                    managed_biguint!(PRESALE_PRICE),
+                    managed_biguint!(PRESALE_MAX_PER_WALLET),
+                );
+            },
+        )
+        .assert_user_error(ERR_PRESALE_BAD_PUBLIC_START_TIMESTAMP);
+}
+
+#[test]
+fn clear_presale_opens_the_sale_to_everyone() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup_presale(&mut setup, &[]);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let presale = sc.get_presale(STARTING_AUCTION_ID).into_option().unwrap();
+
+            assert_eq!(presale.public_start_timestamp, PUBLIC_START_TIMESTAMP);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.clear_presale(STARTING_AUCTION_ID),
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc.get_presale(STARTING_AUCTION_ID).into_option().is_none());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(START_TIMESTAMP);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PUBLIC_PRICE);
+}
@@ -0,0 +1,175 @@
+use apc_sales::{
+    EmptyContract, ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH, ERR_PRICE_SCHEDULE_BAD_TIERS,
+    STARTING_AUCTION_ID,
+};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::MultiValueEncoded;
+use multiversx_sc_scenario::{managed_biguint, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+const PRICE: u64 = 100;
+const QUANTITY: u64 = 10;
+const SECOND_TIER_TIMESTAMP: u64 = 86_400;
+const SECOND_TIER_PRICE: u64 = 150;
+const LAST_TIER_TIMESTAMP: u64 = 2 * 86_400;
+const LAST_TIER_PRICE: u64 = 120;
+
+fn set_price_schedule<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    tiers: &[(u64, u64)],
+    expected_error: Option<&str>,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let mut arg = MultiValueEncoded::new();
+
+            for (from_timestamp, price) in tiers {
+                arg.push((*from_timestamp, managed_biguint!(*price)).into());
+            }
+
+            sc.set_price_schedule(STARTING_AUCTION_ID, arg);
+        },
+    );
+
+    match expected_error {
+        Some(message) => tx_result.assert_user_error(message),
+        None => tx_result.assert_ok(),
+    }
+}
+
+fn check_current_price<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    timestamp: u64,
+    expected_price: u64,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup.blockchain_wrapper.set_block_timestamp(timestamp);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_current_price(STARTING_AUCTION_ID), expected_price);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn current_price_follows_the_schedule() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+    set_price_schedule(
+        &mut setup,
+        &[
+            (SECOND_TIER_TIMESTAMP, SECOND_TIER_PRICE),
+            (LAST_TIER_TIMESTAMP, LAST_TIER_PRICE),
+        ],
+        None,
+    );
+
+    check_current_price(&mut setup, 0, PRICE);
+    check_current_price(&mut setup, SECOND_TIER_TIMESTAMP - 1, PRICE);
+    check_current_price(&mut setup, SECOND_TIER_TIMESTAMP, SECOND_TIER_PRICE);
+    check_current_price(&mut setup, LAST_TIER_TIMESTAMP + 1_000, LAST_TIER_PRICE);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let schedule = sc.get_price_schedule(STARTING_AUCTION_ID);
+
+            assert_eq!(schedule.len(), 2);
+            assert_eq!(schedule.get(0).from_timestamp, SECOND_TIER_TIMESTAMP);
+            assert_eq!(schedule.get(1).price, LAST_TIER_PRICE);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_uses_the_active_tier() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+    set_price_schedule(
+        &mut setup,
+        &[(SECOND_TIER_TIMESTAMP, SECOND_TIER_PRICE)],
+        None,
+    );
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(SECOND_TIER_TIMESTAMP);
+    setup
+        .blockchain_wrapper
+        .set_egld_balance(&setup.user_address, &rust_biguint!(PRICE));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(PRICE),
+            |sc| {
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error(ERR_INVALID_PAYMENT_TOKEN_AMOUNT_MISMATCH);
+
+    setup.buy_in_egld(STARTING_AUCTION_ID, SECOND_TIER_PRICE * 2);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pending_proceeds(STARTING_AUCTION_ID),
+                SECOND_TIER_PRICE * 2
+            );
+            assert_eq!(
+                sc.auctions(STARTING_AUCTION_ID).get().current_quantity,
+                QUANTITY - 2
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn set_price_schedule_fails_if_tiers_are_not_sorted() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+    set_price_schedule(
+        &mut setup,
+        &[
+            (LAST_TIER_TIMESTAMP, LAST_TIER_PRICE),
+            (SECOND_TIER_TIMESTAMP, SECOND_TIER_PRICE),
+        ],
+        Some(ERR_PRICE_SCHEDULE_BAD_TIERS),
+    );
+    set_price_schedule(
+        &mut setup,
+        &[(SECOND_TIER_TIMESTAMP, 0)],
+        Some(ERR_PRICE_SCHEDULE_BAD_TIERS),
+    );
+}
+
+#[test]
+fn empty_schedule_restores_the_auction_price() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+    set_price_schedule(&mut setup, &[(0, SECOND_TIER_PRICE)], None);
+    check_current_price(&mut setup, 0, SECOND_TIER_PRICE);
+
+    set_price_schedule(&mut setup, &[], None);
+    check_current_price(&mut setup, 0, PRICE);
+}
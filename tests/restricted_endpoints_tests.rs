@@ -0,0 +1,289 @@
+use apc_sales::{
+    roles::{Role, RolesModule},
+    EmptyContract, ERR_MISSING_ROLE, ERR_NOT_AUCTION_SELLER, STARTING_AUCTION_ID,
+};
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, ManagedByteArray, MultiValueEncoded};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+/** Calls the endpoint from an address without any role */
+fn check_forbidden<ContractObjBuilder, TxFn>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    tx_fn: TxFn,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+    TxFn: FnOnce(apc_sales::ContractObj<DebugApi>),
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            tx_fn,
+        )
+        .assert_user_error(ERR_MISSING_ROLE);
+}
+
+/** Calls the endpoint on an auction of the owner from an address without any role */
+fn check_not_seller<ContractObjBuilder, TxFn>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    tx_fn: TxFn,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+    TxFn: FnOnce(apc_sales::ContractObj<DebugApi>),
+{
+    setup.create_default_auction_buyable_in_egld(1, 0, 1);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            tx_fn,
+        )
+        .assert_user_error(ERR_NOT_AUCTION_SELLER);
+}
+
+#[test]
+fn add_token_to_auction_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.add_token_to_auction(STARTING_AUCTION_ID);
+    });
+}
+
+#[test]
+fn retire_token_from_auction_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.retire_token_from_auction(STARTING_AUCTION_ID, &managed_biguint!(1));
+    });
+}
+
+#[test]
+fn hide_auction_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.hide_auction(STARTING_AUCTION_ID);
+    });
+}
+
+#[test]
+fn archive_auction_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.archive_auction(STARTING_AUCTION_ID);
+    });
+}
+
+#[test]
+fn set_presale_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.set_presale(
+            STARTING_AUCTION_ID,
+            0,
+            managed_biguint!(1),
+            managed_biguint!(0),
+        );
+    });
+}
+
+#[test]
+fn clear_presale_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.clear_presale(STARTING_AUCTION_ID);
+    });
+}
+
+#[test]
+fn add_to_allowlist_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.add_to_allowlist(STARTING_AUCTION_ID, MultiValueEncoded::new());
+    });
+}
+
+#[test]
+fn remove_from_allowlist_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.remove_from_allowlist(STARTING_AUCTION_ID, MultiValueEncoded::new());
+    });
+}
+
+#[test]
+fn set_allowlist_merkle_root_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.set_allowlist_merkle_root(
+            STARTING_AUCTION_ID,
+            ManagedByteArray::new_from_bytes(&[0u8; 32]),
+        );
+    });
+}
+
+#[test]
+fn clear_allowlist_merkle_root_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.clear_allowlist_merkle_root(STARTING_AUCTION_ID);
+    });
+}
+
+#[test]
+fn set_default_fee_splits_is_forbidden() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_forbidden(&mut setup, |sc| {
+        sc.set_default_fee_splits(MultiValueEncoded::new());
+    });
+}
+
+#[test]
+fn set_platform_fee_is_forbidden() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    let user_address = setup.user_address.clone();
+
+    check_forbidden(&mut setup, |sc| {
+        sc.set_platform_fee(managed_address!(&user_address), 100);
+    });
+}
+
+#[test]
+fn set_auction_fee_splits_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.set_auction_fee_splits(STARTING_AUCTION_ID, MultiValueEncoded::new());
+    });
+}
+
+#[test]
+fn set_honor_royalties_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.set_honor_royalties(STARTING_AUCTION_ID, true);
+    });
+}
+
+#[test]
+fn set_payment_token_price_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.set_payment_token_price(
+            STARTING_AUCTION_ID,
+            EgldOrEsdtTokenIdentifier::egld(),
+            0,
+            managed_biguint!(1),
+        );
+    });
+}
+
+#[test]
+fn update_auction_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.update_auction(
+            STARTING_AUCTION_ID,
+            managed_biguint!(1),
+            0,
+            EgldOrEsdtTokenIdentifier::egld(),
+            0,
+        );
+    });
+}
+
+#[test]
+fn set_price_schedule_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.set_price_schedule(STARTING_AUCTION_ID, MultiValueEncoded::new());
+    });
+}
+
+#[test]
+fn remove_payment_token_is_reserved_to_the_seller() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_not_seller(&mut setup, |sc| {
+        sc.remove_payment_token(STARTING_AUCTION_ID, EgldOrEsdtTokenIdentifier::egld(), 0);
+    });
+}
+
+#[test]
+fn pause_is_forbidden() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_forbidden(&mut setup, |sc| {
+        sc.pause();
+    });
+}
+
+#[test]
+fn unpause_is_forbidden() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_forbidden(&mut setup, |sc| {
+        sc.unpause();
+    });
+}
+
+#[test]
+fn pause_auction_is_forbidden() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_forbidden(&mut setup, |sc| {
+        sc.pause_auction(STARTING_AUCTION_ID);
+    });
+}
+
+#[test]
+fn unpause_auction_is_forbidden() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    check_forbidden(&mut setup, |sc| {
+        sc.unpause_auction(STARTING_AUCTION_ID);
+    });
+}
+
+#[test]
+fn grant_role_is_forbidden() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    let user_address = setup.user_address.clone();
+
+    check_forbidden(&mut setup, |sc| {
+        sc.grant_role(Role::Admin, managed_address!(&user_address));
+    });
+}
+
+#[test]
+fn revoke_role_is_forbidden() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    let owner_address = setup.owner_address.clone();
+
+    check_forbidden(&mut setup, |sc| {
+        sc.revoke_role(Role::Admin, managed_address!(&owner_address));
+    });
+}
@@ -0,0 +1,251 @@
+use apc_sales::{
+    roles::{Role, RolesModule},
+    EmptyContract, ERR_MISSING_ROLE, ERR_NOT_AUCTION_SELLER, ERR_REVOKING_LAST_ADMIN,
+    STARTING_AUCTION_ID,
+};
+use multiversx_sc::types::{Address, BoxedBytes, MultiValueEncoded};
+use multiversx_sc_scenario::{managed_address, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+const PRICE: u64 = 10;
+const QUANTITY: u64 = 5;
+
+fn set_role<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    role: Role,
+    address: &Address,
+    granted: bool,
+    expected_error: Option<&str>,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            if granted {
+                sc.grant_role(role, managed_address!(address));
+            } else {
+                sc.revoke_role(role, managed_address!(address));
+            }
+        },
+    );
+
+    match expected_error {
+        Some(message) => tx_result.assert_user_error(message),
+        None => tx_result.assert_ok(),
+    }
+}
+
+#[test]
+fn deployer_is_admin() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let owner_address = setup.owner_address.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let roles: Vec<Role> = sc
+                .get_roles(managed_address!(&owner_address))
+                .into_iter()
+                .collect();
+
+            assert_eq!(roles, vec![Role::Admin]);
+            assert_eq!(sc.role_holders(Role::Admin).len(), 1);
+            assert!(sc.has_role(Role::Treasurer, managed_address!(&owner_address)));
+        })
+        .assert_ok();
+}
+
+/** Hides the auction of the owner from the user address */
+fn hide_auction_as_user<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    auction_id: u64,
+    expected_error: Option<&str>,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.user_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(0),
+        |sc| sc.hide_auction(auction_id),
+    );
+
+    match expected_error {
+        Some(message) => tx_result.assert_user_error(message),
+        None => tx_result.assert_ok(),
+    }
+}
+
+#[test]
+fn listing_manager_can_only_manage_listings() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+
+    hide_auction_as_user(
+        &mut setup,
+        STARTING_AUCTION_ID,
+        Some(ERR_NOT_AUCTION_SELLER),
+    );
+
+    set_role(&mut setup, Role::ListingManager, &user_address, true, None);
+
+    hide_auction_as_user(&mut setup, STARTING_AUCTION_ID, None);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.set_default_fee_splits(MultiValueEncoded::new()),
+        )
+        .assert_user_error(ERR_MISSING_ROLE);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.withdraw_balance(),
+        )
+        .assert_user_error(ERR_MISSING_ROLE);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let holders: Vec<_> = sc.role_holders(Role::ListingManager).iter().collect();
+
+            assert_eq!(holders, vec![managed_address!(&user_address)]);
+        })
+        .assert_ok();
+
+    set_role(&mut setup, Role::ListingManager, &user_address, false, None);
+
+    hide_auction_as_user(
+        &mut setup,
+        STARTING_AUCTION_ID + 1,
+        Some(ERR_NOT_AUCTION_SELLER),
+    );
+
+    // the retired items go back to the seller, not to the listing manager
+    setup.blockchain_wrapper.check_nft_balance(
+        &setup.owner_address,
+        helpers::DEFAULT_AUCTION_OUTPUT_TOKEN,
+        helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(QUANTITY),
+        Option::Some(&BoxedBytes::empty()),
+    );
+}
+
+#[test]
+fn treasurer_can_withdraw_balance() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let treasurer = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+
+    set_role(&mut setup, Role::Treasurer, &treasurer, true, None);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &treasurer,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.withdraw_balance(),
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&treasurer, &rust_biguint!(PRICE));
+}
+
+#[test]
+fn treasurer_can_set_platform_fee() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let treasurer = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    set_role(&mut setup, Role::Treasurer, &treasurer, true, None);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &treasurer,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.set_platform_fee(managed_address!(&treasurer), 250),
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.platform_fee().get().bps, 250);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn pauser_can_pause() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    set_role(&mut setup, Role::Pauser, &user_address, true, None);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.pause(),
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(sc.paused().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn last_admin_cannot_be_revoked() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let user_address = setup.user_address.clone();
+
+    set_role(
+        &mut setup,
+        Role::Admin,
+        &owner_address,
+        false,
+        Some(ERR_REVOKING_LAST_ADMIN),
+    );
+
+    set_role(&mut setup, Role::Admin, &user_address, true, None);
+    set_role(&mut setup, Role::Admin, &owner_address, false, None);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert!(!sc.has_role(Role::Admin, managed_address!(&owner_address)));
+            assert!(sc.has_role(Role::Admin, managed_address!(&user_address)));
+        })
+        .assert_ok();
+}
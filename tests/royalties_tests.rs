@@ -0,0 +1,185 @@
+use apc_sales::{EmptyContract, STARTING_AUCTION_ID};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{Address, BoxedBytes, EgldOrEsdtTokenIdentifier, MultiValueEncoded};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint, DebugApi};
+
+use crate::helpers::{self, DEFAULT_AUCTION_OUTPUT_NONCE, DEFAULT_AUCTION_OUTPUT_TOKEN};
+
+const PRICE: u64 = 1_000;
+const QUANTITY: u64 = 10;
+const ROYALTIES: u64 = 1_000;
+
+/** Creates an auction of items with 10% royalties for `creator_address` */
+fn create_auction_with_royalties<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    creator_address: &Address,
+    honor_royalties: bool,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup.blockchain_wrapper.set_nft_balance_all_properties(
+        &setup.owner_address,
+        DEFAULT_AUCTION_OUTPUT_TOKEN,
+        DEFAULT_AUCTION_OUTPUT_NONCE,
+        &rust_biguint!(QUANTITY),
+        &BoxedBytes::empty(),
+        ROYALTIES,
+        Some(creator_address),
+        None,
+        None,
+        &[],
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            DEFAULT_AUCTION_OUTPUT_TOKEN,
+            DEFAULT_AUCTION_OUTPUT_NONCE,
+            &rust_biguint!(QUANTITY),
+            |sc| {
+                let auction_id = sc.create_auction(
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    0,
+                    managed_biguint!(PRICE),
+                    0,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+
+                sc.set_honor_royalties(auction_id, honor_royalties);
+            },
+        )
+        .assert_ok();
+}
+
+fn claim_fees<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    payee: &Address,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_tx(payee, &setup.contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.claim_fees();
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_pays_royalties_to_creator() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let creator_address = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    create_auction_with_royalties(&mut setup, &creator_address, true);
+
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * 2);
+
+    claim_fees(&mut setup, &creator_address);
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&creator_address, &rust_biguint!(200));
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_pending_proceeds(STARTING_AUCTION_ID), 1_800u64);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_of_last_items_pays_royalties() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let creator_address = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    create_auction_with_royalties(&mut setup, &creator_address, true);
+
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * QUANTITY);
+
+    claim_fees(&mut setup, &creator_address);
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&creator_address, &rust_biguint!(PRICE * QUANTITY / 10));
+}
+
+#[test]
+fn buy_ignores_royalties_if_not_opted_in() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let creator_address = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    create_auction_with_royalties(&mut setup, &creator_address, false);
+
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_pending_proceeds(STARTING_AUCTION_ID), PRICE);
+            assert_eq!(
+                sc.get_accrued_fees(managed_address!(&creator_address))
+                    .into_iter()
+                    .count(),
+                0
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn fee_splits_apply_after_royalties() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+    let creator_address = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+    let treasury_address = setup
+        .blockchain_wrapper
+        .create_user_account(&rust_biguint!(0));
+
+    create_auction_with_royalties(&mut setup, &creator_address, true);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut fee_splits = MultiValueEncoded::new();
+                fee_splits.push((managed_address!(&treasury_address), 5_000u64).into());
+
+                sc.set_default_fee_splits(fee_splits);
+            },
+        )
+        .assert_ok();
+
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+
+    claim_fees(&mut setup, &creator_address);
+    claim_fees(&mut setup, &treasury_address);
+
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&creator_address, &rust_biguint!(100));
+    setup
+        .blockchain_wrapper
+        .check_egld_balance(&treasury_address, &rust_biguint!(450));
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_pending_proceeds(STARTING_AUCTION_ID), 450u64);
+        })
+        .assert_ok();
+}
@@ -0,0 +1,11 @@
+#[test]
+#[ignore = "needs output/apc_sales.wasm and the Go VM, see run_go_scenarios.sh"]
+fn empty_go() {
+    multiversx_sc_scenario::run_go("scenarios/empty.scen.json");
+}
+
+#[test]
+#[ignore = "needs output/apc_sales.wasm and the Go VM, see run_go_scenarios.sh"]
+fn hide_auction_go() {
+    multiversx_sc_scenario::run_go("scenarios/hide_auction.scen.json");
+}
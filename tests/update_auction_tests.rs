@@ -0,0 +1,168 @@
+use apc_sales::{
+    auction::BondingCurve, EmptyContract, ERR_CREATE_AUCTION_BAD_PRICE,
+    ERR_UPDATE_AUCTION_HAS_SALES, ERR_WRONG_AUCTION_KIND, STARTING_AUCTION_ID,
+};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::EgldOrEsdtTokenIdentifier;
+use multiversx_sc_scenario::{managed_biguint, managed_token_id_wrapped, rust_biguint, DebugApi};
+
+use crate::helpers;
+
+const PRICE: u64 = 100;
+const START_TIMESTAMP: u64 = 10;
+const QUANTITY: u64 = 10;
+const EGLD_TOKEN: &[u8] = b"EGLD";
+const STABLECOIN_TOKEN: &[u8] = b"USDC-aaaaaa";
+const STABLECOIN_PRICE: u64 = 5;
+
+fn update_auction<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    price: u64,
+    start_timestamp: u64,
+    input_token_id: &[u8],
+    expected_error: Option<&str>,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    let tx_result = setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(0),
+        |sc| {
+            let input_token_id = if input_token_id == EGLD_TOKEN {
+                EgldOrEsdtTokenIdentifier::egld()
+            } else {
+                managed_token_id_wrapped!(input_token_id)
+            };
+
+            sc.update_auction(
+                STARTING_AUCTION_ID,
+                managed_biguint!(price),
+                start_timestamp,
+                input_token_id,
+                0,
+            );
+        },
+    );
+
+    match expected_error {
+        Some(message) => tx_result.assert_user_error(message),
+        None => tx_result.assert_ok(),
+    }
+}
+
+#[test]
+fn update_auction_works() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(PRICE, START_TIMESTAMP, QUANTITY);
+
+    update_auction(&mut setup, STABLECOIN_PRICE, 0, STABLECOIN_TOKEN, None);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let auction = sc.auctions(STARTING_AUCTION_ID).get();
+
+            assert_eq!(auction.price, STABLECOIN_PRICE);
+            assert_eq!(auction.start_timestamp, 0);
+            assert_eq!(
+                auction.input_token_id,
+                managed_token_id_wrapped!(STABLECOIN_TOKEN)
+            );
+
+            // the payment token index follows the auction
+            assert!(sc
+                .auctions_by_payment_token(&managed_token_id_wrapped!(STABLECOIN_TOKEN))
+                .contains(&STARTING_AUCTION_ID));
+            assert!(!sc
+                .auctions_by_payment_token(&EgldOrEsdtTokenIdentifier::egld())
+                .contains(&STARTING_AUCTION_ID));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn update_auction_fails_to_change_payment_token_after_sales() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+
+    update_auction(
+        &mut setup,
+        STABLECOIN_PRICE,
+        0,
+        STABLECOIN_TOKEN,
+        Some(ERR_UPDATE_AUCTION_HAS_SALES),
+    );
+
+    // the price can still change
+    update_auction(&mut setup, PRICE * 2, 0, EGLD_TOKEN, None);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.auctions(STARTING_AUCTION_ID).get().price, PRICE * 2);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn update_auction_fails_if_price_is_zero() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+
+    update_auction(
+        &mut setup,
+        0,
+        0,
+        EGLD_TOKEN,
+        Some(ERR_CREATE_AUCTION_BAD_PRICE),
+    );
+}
+
+#[test]
+fn update_auction_fails_for_english_auction() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup.create_default_english_auction_in_egld(PRICE, 1, 0, 100, 1);
+
+    update_auction(
+        &mut setup,
+        PRICE,
+        0,
+        EGLD_TOKEN,
+        Some(ERR_WRONG_AUCTION_KIND),
+    );
+}
+
+#[test]
+fn update_auction_fails_for_bonding_curve_auction() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup
+        .create_default_auction_with(QUANTITY, |sc| {
+            let _ = sc.create_bonding_curve_auction(
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                managed_biguint!(PRICE),
+                BondingCurve::Linear {
+                    increment: managed_biguint!(1),
+                },
+                0,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    update_auction(
+        &mut setup,
+        PRICE * 2,
+        0,
+        EGLD_TOKEN,
+        Some(ERR_WRONG_AUCTION_KIND),
+    );
+}
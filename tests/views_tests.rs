@@ -1,11 +1,13 @@
 use crate::helpers;
 use apc_sales::{
-    auction::{Auction, AuctionStats},
+    auction::{Auction, AuctionKind, AuctionStats, AuctionStatus, SaleStatus},
     EmptyContract, ERR_INVALID_AUCTION_ID, STARTING_AUCTION_ID,
 };
+use multiversx_sc::codec::multi_types::OptionalValue;
 use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, TokenIdentifier};
 use multiversx_sc_scenario::{
-    managed_biguint, managed_token_id, managed_token_id_wrapped, rust_biguint, DebugApi,
+    managed_address, managed_biguint, managed_token_id, managed_token_id_wrapped, rust_biguint,
+    DebugApi,
 };
 
 #[test]
@@ -30,6 +32,8 @@ fn view_get_auction_works() {
 
     setup.create_default_auction_buyable_in_egld(PRICE, START_TIMESTAMP, INITIAL_QUANTITY);
 
+    let owner_address = setup.owner_address.clone();
+
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
@@ -43,12 +47,18 @@ fn view_get_auction_works() {
                     output_token_nonce: helpers::DEFAULT_AUCTION_OUTPUT_NONCE,
                     price: managed_biguint!(PRICE),
                     start_timestamp: START_TIMESTAMP,
+                    end_timestamp: 0,
                     input_token_id: EgldOrEsdtTokenIdentifier::egld(),
                     input_token_nonce: 0,
                     max_quantity: managed_biguint!(INITIAL_QUANTITY),
                     current_quantity: managed_biguint!(INITIAL_QUANTITY),
+                    max_per_wallet: managed_biguint!(0),
+                    status: AuctionStatus::Active,
+                    seller: managed_address!(&owner_address),
                 },
                 id: STARTING_AUCTION_ID,
+                kind: AuctionKind::FixedPrice,
+                status: SaleStatus::Upcoming,
             };
 
             assert_eq!(actual_auction_stats, expected_auction_stats);
@@ -103,10 +113,12 @@ fn view_get_all_auctions_works() {
         )
     }
 
+    let owner_address = setup.owner_address.clone();
+
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
-            let actual_auctions = sc.get_all_auctions_stats();
+            let actual_auctions = sc.get_all_auctions_stats(OptionalValue::None);
 
             assert_eq!(actual_auctions.len(), expected_auctions.len());
 
@@ -123,9 +135,15 @@ fn view_get_all_auctions_works() {
                         max_quantity: managed_biguint!(expected_auction.quantity),
                         price: managed_biguint!(expected_auction.price),
                         start_timestamp: expected_auction.start_timestamp,
+                        end_timestamp: 0,
                         current_quantity: managed_biguint!(expected_auction.quantity),
+                        max_per_wallet: managed_biguint!(0),
+                        status: AuctionStatus::Active,
+                        seller: managed_address!(&owner_address),
                     },
                     id: index as u64 + 1,
+                    kind: AuctionKind::FixedPrice,
+                    status: SaleStatus::Upcoming,
                 };
 
                 assert_eq!(actual_auction_stats, expected_auction_stats);
@@ -155,7 +173,11 @@ fn current_quantity_updated_after_buy() {
             &setup.contract_wrapper,
             &rust_biguint!(PRICE * BUY_QUANTITY),
             |sc| {
-                sc.buy(STARTING_AUCTION_ID);
+                sc.buy(
+                    STARTING_AUCTION_ID,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
 
                 let auction = sc.get_auction(STARTING_AUCTION_ID);
 
@@ -194,3 +216,177 @@ fn create_auction_should_not_reanimate() {
         })
         .assert_ok();
 }
+
+#[test]
+fn view_pending_proceeds_follows_buy_and_withdraw() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 10;
+    const QUANTITY: u64 = 10;
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * 2);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_pending_proceeds(STARTING_AUCTION_ID), PRICE * 2);
+        })
+        .assert_ok();
+
+    setup.withdraw_balance();
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_pending_proceeds(STARTING_AUCTION_ID), PRICE);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn view_pending_proceeds_fail_if_invalid_id() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            sc.get_pending_proceeds(STARTING_AUCTION_ID);
+        })
+        .assert_user_error(ERR_INVALID_AUCTION_ID);
+}
+
+fn check_status<ContractObjBuilder>(
+    setup: &mut helpers::ContractSetup<ContractObjBuilder>,
+    auction_id: u64,
+    expected_status: SaleStatus,
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> apc_sales::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_auction_stats(auction_id).status, expected_status);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn view_status_follows_the_sale() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 10;
+    const QUANTITY: u64 = 2;
+    const START_TIMESTAMP: u64 = 10;
+    const END_TIMESTAMP: u64 = 20;
+
+    // 1. live until sold out
+    setup.create_default_timed_auction_buyable_in_egld(
+        PRICE,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+
+    // 2. ends with items left
+    setup.create_default_timed_auction_buyable_in_egld(
+        PRICE,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+
+    // 3. hidden
+    setup.create_default_timed_auction_buyable_in_egld(
+        PRICE,
+        START_TIMESTAMP,
+        END_TIMESTAMP,
+        QUANTITY,
+    );
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| sc.hide_auction(STARTING_AUCTION_ID + 2),
+        )
+        .assert_ok();
+
+    check_status(&mut setup, STARTING_AUCTION_ID, SaleStatus::Upcoming);
+    check_status(&mut setup, STARTING_AUCTION_ID + 2, SaleStatus::Hidden);
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(START_TIMESTAMP);
+    check_status(&mut setup, STARTING_AUCTION_ID, SaleStatus::Live);
+
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * QUANTITY);
+    check_status(&mut setup, STARTING_AUCTION_ID, SaleStatus::SoldOut);
+    check_status(&mut setup, STARTING_AUCTION_ID + 1, SaleStatus::Live);
+
+    setup.blockchain_wrapper.set_block_timestamp(END_TIMESTAMP);
+    check_status(&mut setup, STARTING_AUCTION_ID, SaleStatus::SoldOut);
+    check_status(&mut setup, STARTING_AUCTION_ID + 1, SaleStatus::Ended);
+}
+
+#[test]
+fn view_wallet_purchases_works() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 10;
+    const QUANTITY: u64 = 10;
+    const MAX_PER_WALLET: u64 = 4;
+
+    setup.create_default_limited_auction_buyable_in_egld(PRICE, QUANTITY, MAX_PER_WALLET);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE);
+
+    let user_address = setup.user_address.clone();
+    let owner_address = setup.owner_address.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let (purchased, buyable) = sc
+                .get_wallet_purchases(STARTING_AUCTION_ID, managed_address!(&user_address))
+                .into_tuple();
+
+            assert_eq!(purchased, 1);
+            assert_eq!(buyable, MAX_PER_WALLET - 1);
+
+            let (purchased, buyable) = sc
+                .get_wallet_purchases(STARTING_AUCTION_ID, managed_address!(&owner_address))
+                .into_tuple();
+
+            assert_eq!(purchased, 0);
+            assert_eq!(buyable, MAX_PER_WALLET);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn view_wallet_purchases_without_limit_returns_remaining_items() {
+    let mut setup = helpers::setup_contract(apc_sales::contract_obj);
+
+    const PRICE: u64 = 10;
+    const QUANTITY: u64 = 10;
+
+    setup.create_default_auction_buyable_in_egld(PRICE, 0, QUANTITY);
+    setup.buy_in_egld(STARTING_AUCTION_ID, PRICE * 2);
+
+    let user_address = setup.user_address.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let (purchased, buyable) = sc
+                .get_wallet_purchases(STARTING_AUCTION_ID, managed_address!(&user_address))
+                .into_tuple();
+
+            assert_eq!(purchased, 2);
+            assert_eq!(buyable, QUANTITY - 2);
+        })
+        .assert_ok();
+}
@@ -0,0 +1,34 @@
+use multiversx_sc::contract_base::ContractAbiProvider;
+
+const WASM_LIB_PATH: &str = "wasm/src/lib.rs";
+
+/** Names listed in the `endpoints!` macro of the generated wasm crate */
+fn get_wasm_endpoints() -> Vec<String> {
+    let wasm_lib = std::fs::read_to_string(WASM_LIB_PATH).expect("Can't read the wasm crate");
+    let endpoints = wasm_lib
+        .split("apc_sales\n    (")
+        .nth(1)
+        .and_then(|rest| rest.split(')').next())
+        .expect("No endpoints! macro in the wasm crate");
+
+    return endpoints
+        .split_whitespace()
+        .map(|name| name.to_string())
+        .collect();
+}
+
+#[test]
+fn wasm_exports_every_abi_endpoint() {
+    let mut wasm_endpoints = get_wasm_endpoints();
+    let mut abi_endpoints: Vec<String> = apc_sales::AbiProvider::abi()
+        .endpoints
+        .iter()
+        .map(|endpoint| endpoint.name.to_string())
+        .collect();
+
+    wasm_endpoints.sort();
+    abi_endpoints.sort();
+
+    // regenerate the wasm crate with `cargo run build` in `meta` when this fails
+    assert_eq!(wasm_endpoints, abi_endpoints);
+}
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            8
+// Endpoints:                           73
 // Async Callback (empty):               1
-// Total number of exported functions:  10
+// Total number of exported functions:  75
 
 #![no_std]
 #![feature(alloc_error_handler, lang_items)]
@@ -18,14 +18,79 @@ multiversx_sc_wasm_adapter::panic_handler!();
 multiversx_sc_wasm_adapter::endpoints! {
     apc_sales
     (
+        isPaused
         getNextAuctionId
+        getAllowlist
+        getDefaultFeeSplits
+        isHonoringRoyalties
+        getPlatformFee
         createAuction
+        createEnglishAuction
+        createMysteryBoxAuction
+        createDutchAuction
+        createBondingCurveAuction
         addTokenToAuction
         retireTokenFromAuction
         withdrawBalance
+        setPaymentTokenPrice
+        removePaymentToken
+        updateAuction
+        setDefaultFeeSplits
+        setPlatformFee
+        setAuctionFeeSplits
+        setHonorRoyalties
+        claimFees
+        hideAuction
+        archiveAuction
+        pause
+        unpause
+        pauseAuction
+        unpauseAuction
+        setPresale
+        clearPresale
+        setPriceSchedule
+        addToAllowlist
+        removeFromAllowlist
+        setAllowlistMerkleRoot
+        clearAllowlistMerkleRoot
         buy
+        buyMany
+        buyCart
+        bid
+        claim
+        isAuctionPaused
         getAuctionStats
+        getWalletPurchases
+        isEligible
+        getAllowlistMerkleRoot
+        getFeeSplits
+        getAccruedFees
+        getSellerEarnings
+        getPaymentTokens
+        getPendingProceedsPerToken
+        getMysteryBoxPoolSize
+        getBundle
+        getPresale
+        getCurrentPrice
+        quote
+        getBondingCurve
+        getPriceSchedule
+        getDutchAuction
+        getEnglishAuction
+        getMinimumBid
+        getPendingProceeds
         getAllAuctionStats
+        getAuctionsPage
+        getActiveAuctions
+        getAuctionsBySeller
+        getAuctionsByOutputToken
+        getAuctionsByPaymentToken
+        getAuctionsByStatus
+        getRoleHolders
+        grantRole
+        revokeRole
+        hasRole
+        getRoles
     )
 }
 